@@ -29,8 +29,49 @@ pub trait AndThen<O, E>: Sized {
     where
         F: FnMut(O) -> Result<O2, E>;
 
+    /// Map oks selectively, possibly converting them to errors of a wider type
+    ///
+    /// Same as [`and_then_ok`](AndThen::and_then_ok), but the error type is unified
+    /// into `E2`, the way the `?` operator does, so both types of errors end up
+    /// as the same `Err(E2)`:
+    /// - the original errors are converted with [`Into`];
+    /// - the errors produced by the function are yielded as is.
+    ///
+    /// ```
+    /// use resiter::and_then::AndThen;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error {
+    ///     Read(&'static str),
+    ///     Odd(u32),
+    /// }
+    ///
+    /// impl From<&'static str> for Error {
+    ///     fn from(e: &'static str) -> Self {
+    ///         Error::Read(e)
+    ///     }
+    /// }
+    ///
+    /// let halves: Vec<_> = vec![Ok(2), Err("eof"), Ok(3)]
+    ///     .into_iter()
+    ///     .and_then_ok_from(|i| if i % 2 == 0 { Ok(i / 2) } else { Err(Error::Odd(i)) })
+    ///     .collect();
+    ///
+    /// assert_eq!(halves, [Ok(1), Err(Error::Read("eof")), Err(Error::Odd(3))]);
+    /// ```
+    fn and_then_ok_from<F, O2, E2>(self, _: F) -> AndThenOkFrom<Self, F>
+    where
+        E: Into<E2>,
+        F: FnMut(O) -> Result<O2, E2>;
+
     /// Map errors selectively, possibly converting them to Oks
     ///
+    /// This is the error-channel counterpart of [`and_then_ok`](AndThen::and_then_ok):
+    /// the `Ok` values pass through, while every `Err` is replaced with the result
+    /// of the function, the same way [`Result::or_else`] does.
+    /// The error type could be changed along the way;
+    /// if it stays the same, [`or_else_ok`](AndThen::or_else_ok) is an alias for this method.
+    ///
     /// ```
     /// use resiter::and_then::AndThen;
     /// use std::str::FromStr;
@@ -48,9 +89,99 @@ pub trait AndThen<O, E>: Sized {
     /// assert_eq!(mapped[4], Ok(4));
     /// assert_eq!(mapped[5], Ok(5));
     /// ```
+    ///
+    /// Recover only the specific errors, converting the rest into another type:
+    /// ```
+    /// use resiter::and_then::AndThen;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Fatal {
+    ///     Broken,
+    ///     Other(String),
+    /// }
+    ///
+    /// let recovered: Vec<_> = vec![Ok(1), Err("missing"), Ok(3), Err("broken"), Err("eof")]
+    ///     .into_iter()
+    ///     .and_then_err(|e| match e {
+    ///         "missing" => Ok(0),
+    ///         "broken" => Err(Fatal::Broken),
+    ///         other => Err(Fatal::Other(other.to_owned())),
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     recovered,
+    ///     [
+    ///         Ok(1),
+    ///         Ok(0),
+    ///         Ok(3),
+    ///         Err(Fatal::Broken),
+    ///         Err(Fatal::Other("eof".to_owned()))
+    ///     ]
+    /// );
+    /// ```
     fn and_then_err<F, E2>(self, _: F) -> AndThenErr<Self, F>
     where
         F: FnMut(E) -> Result<O, E2>;
+
+    /// Recover from errors selectively, like [`Result::or_else`] does
+    ///
+    /// Unlike [`map_err`][crate::map::Map::map_err], which always produces another error,
+    /// the function could turn an error back into an `Ok` value (or fail again).
+    /// This is the same as [`and_then_err`](AndThen::and_then_err)
+    /// keeping the original error type, so prefer the latter
+    /// if the recovered errors should change their type.
+    ///
+    /// ```
+    /// use resiter::and_then::AndThen;
+    ///
+    /// let recovered: Vec<_> = vec![Ok(1), Err("missing"), Ok(3), Err("broken")]
+    ///     .into_iter()
+    ///     .or_else_ok(|e| if e == "missing" { Ok(0) } else { Err(e) })
+    ///     .collect();
+    ///
+    /// assert_eq!(recovered, [Ok(1), Ok(0), Ok(3), Err("broken")]);
+    /// ```
+    fn or_else_ok<F>(self, _: F) -> OrElseOk<Self, F>
+    where
+        F: FnMut(E) -> Result<O, E>;
+
+    /// Recover from the specific errors only, leaving the rest of them intact
+    ///
+    /// The function only borrows the error: if it returns `Some` value,
+    /// the `Err` is replaced with that `Ok`, otherwise the original `Err` is yielded unchanged.
+    /// This is convenient when the errors should not be reconstructed,
+    /// as it is required by [`or_else_ok`](AndThen::or_else_ok).
+    ///
+    /// ```
+    /// use resiter::and_then::AndThen;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error {
+    ///     NotFound,
+    ///     Failed(String),
+    /// }
+    ///
+    /// let recovered: Vec<_> = vec![
+    ///     Ok(1),
+    ///     Err(Error::NotFound),
+    ///     Err(Error::Failed("disk".to_owned())),
+    /// ]
+    /// .into_iter()
+    /// .recover_with(|e| match e {
+    ///     Error::NotFound => Some(0),
+    ///     Error::Failed(_) => None,
+    /// })
+    /// .collect();
+    ///
+    /// assert_eq!(
+    ///     recovered,
+    ///     [Ok(1), Ok(0), Err(Error::Failed("disk".to_owned()))]
+    /// );
+    /// ```
+    fn recover_with<F>(self, _: F) -> RecoverWith<Self, F>
+    where
+        F: FnMut(&E) -> Option<O>;
 }
 
 impl<I, O, E> AndThen<O, E> for I
@@ -65,6 +196,15 @@ where
         AndThenOk { iter: self, f }
     }
 
+    #[inline]
+    fn and_then_ok_from<F, O2, E2>(self, f: F) -> AndThenOkFrom<Self, F>
+    where
+        E: Into<E2>,
+        F: FnMut(O) -> Result<O2, E2>,
+    {
+        AndThenOkFrom { iter: self, f }
+    }
+
     #[inline]
     fn and_then_err<F, E2>(self, f: F) -> AndThenErr<Self, F>
     where
@@ -72,8 +212,25 @@ where
     {
         AndThenErr { iter: self, f }
     }
+
+    #[inline]
+    fn or_else_ok<F>(self, f: F) -> OrElseOk<Self, F>
+    where
+        F: FnMut(E) -> Result<O, E>,
+    {
+        AndThenErr { iter: self, f }
+    }
+
+    #[inline]
+    fn recover_with<F>(self, f: F) -> RecoverWith<Self, F>
+    where
+        F: FnMut(&E) -> Option<O>,
+    {
+        RecoverWith { iter: self, f }
+    }
 }
 
+#[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct AndThenOk<I, F> {
     iter: I,
@@ -101,12 +258,74 @@ where
     }
 }
 
+impl<I, O, E, O2, F> DoubleEndedIterator for AndThenOk<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<O2, E>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.iter.next_back() {
+            Some(Ok(o)) => Some((self.f)(o)),
+            Some(Err(e)) => Some(Err(e)),
+            None => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct AndThenOkFrom<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, O, E, O2, E2, F> Iterator for AndThenOkFrom<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E: Into<E2>,
+    F: FnMut(O) -> Result<O2, E2>,
+{
+    type Item = Result<O2, E2>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(Ok(o)) => Some((self.f)(o)),
+            Some(Err(e)) => Some(Err(e.into())),
+            None => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, O, E, O2, E2, F> DoubleEndedIterator for AndThenOkFrom<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    E: Into<E2>,
+    F: FnMut(O) -> Result<O2, E2>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.iter.next_back() {
+            Some(Ok(o)) => Some((self.f)(o)),
+            Some(Err(e)) => Some(Err(e.into())),
+            None => None,
+        }
+    }
+}
+
+#[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct AndThenErr<I, F> {
     iter: I,
     f: F,
 }
 
+/// The adapter returned by [`or_else_ok`](AndThen::or_else_ok)
+pub type OrElseOk<I, F> = AndThenErr<I, F>;
+
 impl<I, O, E, E2, F> Iterator for AndThenErr<I, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -127,3 +346,30 @@ where
         self.iter.size_hint()
     }
 }
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct RecoverWith<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, O, E, F> Iterator for RecoverWith<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&E) -> Option<O>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next()? {
+            Ok(o) => Some(Ok(o)),
+            Err(e) => Some((self.f)(&e).ok_or(e)),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -68,6 +68,7 @@ where
     }
 }
 
+#[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct AndThenFilterOk<I, F> {
     iter: I,
@@ -0,0 +1,94 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#[cfg(not(test))]
+use core::fmt::Debug;
+#[cfg(test)]
+use std::fmt::Debug;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to check the invariants in debug builds.
+pub trait Assert<O, E>: Sized {
+    /// Pass all the items as is, panicking on the first `Err` in debug builds.
+    ///
+    /// Same as [`debug_assert!`], the check is only performed
+    /// when the `debug_assertions` are enabled: in release builds
+    /// the errors are silently forwarded like any other item.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the [`Debug`] output of the error
+    /// if an `Err` is encountered, and `debug_assertions` are enabled.
+    ///
+    /// ```
+    /// use resiter::assert::Assert;
+    ///
+    /// let parsed: Vec<Result<u8, _>> = ["1", "2", "3"]
+    ///     .iter()
+    ///     .map(|txt| txt.parse())
+    ///     .assert_all_ok()
+    ///     .collect();
+    ///
+    /// assert_eq!(parsed, [Ok(1), Ok(2), Ok(3)]);
+    /// ```
+    fn assert_all_ok(self) -> AssertAllOk<Self>
+    where
+        E: Debug;
+}
+
+impl<I, O, E> Assert<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn assert_all_ok(self) -> AssertAllOk<Self>
+    where
+        E: Debug,
+    {
+        AssertAllOk { iter: self }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct AssertAllOk<I> {
+    iter: I,
+}
+
+impl<I, O, E> Iterator for AssertAllOk<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E: Debug,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+        if let Some(Err(ref e)) = item {
+            debug_assert!(false, "assert_all_ok: unexpected error: {:?}", e);
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "assert_all_ok: unexpected error: \"broken\"")]
+    fn test_assert_all_ok_panics_on_error() {
+        let mut iter = vec![Ok(1), Err("broken")].into_iter().assert_all_ok();
+
+        assert_eq!(iter.next(), Some(Ok(1)));
+        let _ = iter.next();
+    }
+}
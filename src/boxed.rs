@@ -0,0 +1,116 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use alloc::boxed::Box;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to erase the type of the iterator.
+pub trait Boxed<O, E>: Sized {
+    /// Put the iterator into a [`Box`], hiding its concrete type.
+    ///
+    /// The returned [`BoxedResults`] is an `Iterator<Item = Result<O, E>>` itself,
+    /// so all the extension methods are still available on it.
+    /// This is useful to store the different pipelines in the same variable or field.
+    ///
+    /// ```
+    /// use resiter::boxed::{Boxed, BoxedResults};
+    /// use resiter::filter::Filter;
+    /// use resiter::map::Map;
+    ///
+    /// type Numbers<'a> = BoxedResults<'a, i32, &'static str>;
+    ///
+    /// fn numbers<'a>(source: &'a [Result<i32, &'static str>], even: bool) -> Numbers<'a> {
+    ///     let source = source.iter().cloned();
+    ///     if even {
+    ///         source.filter_ok(|i| i % 2 == 0).boxed_results()
+    ///     } else {
+    ///         source.map_ok(|i| i * 10).boxed_results()
+    ///     }
+    /// }
+    ///
+    /// let source = [Ok(1), Ok(2), Err("a")];
+    /// assert_eq!(numbers(&source, true).collect::<Vec<_>>(), [Ok(2), Err("a")]);
+    ///
+    /// let changed: Vec<_> = numbers(&source, false).map_ok(|i| i + 1).collect();
+    /// assert_eq!(changed, [Ok(11), Ok(21), Err("a")]);
+    /// ```
+    fn boxed_results<'a>(self) -> BoxedResults<'a, O, E>
+    where
+        Self: 'a;
+}
+
+impl<I, O, E> Boxed<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn boxed_results<'a>(self) -> BoxedResults<'a, O, E>
+    where
+        Self: 'a,
+    {
+        BoxedResults(Box::new(self))
+    }
+}
+
+/// The type-erased `Iterator<Item = Result<O, E>>` returned by
+/// [`boxed_results`](Boxed::boxed_results).
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct BoxedResults<'a, O, E>(Box<dyn Iterator<Item = Result<O, E>> + 'a>);
+
+impl<'a, O, E> Iterator for BoxedResults<'a, O, E> {
+    type Item = Result<O, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use and_then::AndThen;
+    use filter::Filter;
+
+    struct Pipeline<'a> {
+        results: BoxedResults<'a, u32, &'static str>,
+    }
+
+    #[test]
+    fn test_boxed_results_in_a_field() {
+        let source = vec![Ok(1), Ok(2), Err("a"), Ok(3)];
+
+        let pipelines = vec![
+            Pipeline {
+                results: source
+                    .clone()
+                    .into_iter()
+                    .filter_ok(|&i| i > 1)
+                    .boxed_results(),
+            },
+            Pipeline {
+                results: source
+                    .into_iter()
+                    .and_then_ok(|i| if i < 3 { Ok(i) } else { Err("big") })
+                    .boxed_results(),
+            },
+        ];
+
+        let collected: Vec<Vec<_>> = pipelines.into_iter().map(|p| p.results.collect()).collect();
+        assert_eq!(
+            collected,
+            [
+                vec![Ok(2), Err("a"), Ok(3)],
+                vec![Ok(1), Ok(2), Err("a"), Err("big")]
+            ]
+        );
+    }
+}
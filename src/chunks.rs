@@ -0,0 +1,452 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#[cfg(not(test))]
+use core::{iter::Peekable, mem};
+#[cfg(test)]
+use std::{iter::Peekable, mem};
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to group the `Ok` or `Err` values into `Vec`s.
+pub trait Chunks<O, E>: Sized {
+    /// Group the consecutive `Ok` values into the `Vec`s of `size` elements,
+    /// while forwarding every `Err` as is.
+    ///
+    /// When an `Err` appears, the already accumulated (partial) chunk is yielded first,
+    /// and then the `Err` itself. The last chunk could also be shorter than `size`
+    /// if there are not enough `Ok` values left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ```
+    /// use resiter::chunks::Chunks;
+    ///
+    /// let chunks: Vec<_> = vec![Ok(1), Ok(2), Ok(3), Err("a"), Ok(4), Ok(5), Ok(6)]
+    ///     .into_iter()
+    ///     .chunk_ok(2)
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     chunks,
+    ///     [Ok(vec![1, 2]), Ok(vec![3]), Err("a"), Ok(vec![4, 5]), Ok(vec![6])]
+    /// );
+    /// ```
+    fn chunk_ok(self, size: usize) -> ChunkOk<Self, O, E>;
+
+    /// Yield the overlapping windows of `size` consecutive `Ok` values,
+    /// while forwarding every `Err` as is.
+    ///
+    /// An `Err` breaks the window: all the `Ok` values seen before it are discarded,
+    /// so the next window is only built from the `Ok`s following the error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ```
+    /// use resiter::chunks::Chunks;
+    ///
+    /// let windows: Vec<_> = vec![Ok(1), Ok(2), Ok(3), Err("a"), Ok(4), Ok(5)]
+    ///     .into_iter()
+    ///     .windows_ok(2)
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     windows,
+    ///     [Ok(vec![1, 2]), Ok(vec![2, 3]), Err("a"), Ok(vec![4, 5])]
+    /// );
+    /// ```
+    fn windows_ok(self, size: usize) -> WindowsOk<Self, O>
+    where
+        O: Clone;
+
+    /// Group every run of consecutive `Ok` values into a `Vec`,
+    /// while forwarding every `Err` as is, between the groups.
+    ///
+    /// This is the inverse of the [`flatten_ok`](crate::flatten::Flatten::flatten_ok):
+    /// same as [`chunk_ok`](Chunks::chunk_ok) without the limit on the chunks size,
+    /// so the chunks are only split by the errors. The empty chunks are never yielded.
+    ///
+    /// ```
+    /// use resiter::chunks::Chunks;
+    ///
+    /// let segments: Vec<_> = vec![Ok(1), Ok(2), Err("a"), Err("b"), Ok(3)]
+    ///     .into_iter()
+    ///     .chunk_by_err()
+    ///     .collect();
+    ///
+    /// assert_eq!(segments, [Ok(vec![1, 2]), Err("a"), Err("b"), Ok(vec![3])]);
+    /// ```
+    fn chunk_by_err(self) -> ChunkByErr<Self, O, E>;
+
+    /// Collapse every run of consecutive `Err` values into a single error,
+    /// produced by the `combine` function, while forwarding every `Ok` as is.
+    ///
+    /// Note the changed error type: the items are `Result<O, A>`,
+    /// where `A` is the type returned by `combine`.
+    ///
+    /// The errors are buffered until the next `Ok` (or the end of the iterator)
+    /// and only then passed to `combine`, so the aggregated error is always yielded
+    /// before the `Ok` which ended the run.
+    /// The `combine` function is never called with an empty `Vec`.
+    ///
+    /// ```
+    /// use resiter::chunks::Chunks;
+    ///
+    /// let batched: Vec<_> = vec![Ok(1), Err("a"), Err("b"), Ok(2), Err("c")]
+    ///     .into_iter()
+    ///     .batch_errors(|errors| errors.join(", "))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     batched,
+    ///     [Ok(1), Err("a, b".to_string()), Ok(2), Err("c".to_string())]
+    /// );
+    /// ```
+    fn batch_errors<A, F>(self, combine: F) -> BatchErrors<Self, F>
+    where
+        Self: Iterator,
+        F: FnMut(Vec<E>) -> A;
+}
+
+impl<I, O, E> Chunks<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn chunk_ok(self, size: usize) -> ChunkOk<Self, O, E> {
+        assert!(size != 0, "chunk size must be non-zero");
+        ChunkOk {
+            iter: self,
+            size,
+            chunk: Vec::with_capacity(size),
+            pending_err: None,
+        }
+    }
+
+    #[inline]
+    fn windows_ok(self, size: usize) -> WindowsOk<Self, O>
+    where
+        O: Clone,
+    {
+        assert!(size != 0, "window size must be non-zero");
+        WindowsOk {
+            iter: self,
+            size,
+            window: VecDeque::with_capacity(size),
+        }
+    }
+
+    #[inline]
+    fn chunk_by_err(self) -> ChunkByErr<Self, O, E> {
+        ChunkByErr {
+            iter: self,
+            chunk: Vec::new(),
+            pending_err: None,
+        }
+    }
+
+    #[inline]
+    fn batch_errors<A, F>(self, combine: F) -> BatchErrors<Self, F>
+    where
+        F: FnMut(Vec<E>) -> A,
+    {
+        BatchErrors {
+            iter: self.peekable(),
+            combine,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ChunkOk<I, O, E> {
+    iter: I,
+    size: usize,
+    chunk: Vec<O>,
+    pending_err: Option<E>,
+}
+
+impl<I, O, E> ChunkOk<I, O, E> {
+    fn take_chunk(&mut self) -> Vec<O> {
+        mem::replace(&mut self.chunk, Vec::with_capacity(self.size))
+    }
+}
+
+impl<I, O, E> Iterator for ChunkOk<I, O, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<Vec<O>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(e) = self.pending_err.take() {
+            return Some(Err(e));
+        }
+
+        loop {
+            match self.iter.next() {
+                Some(Ok(x)) => {
+                    self.chunk.push(x);
+                    if self.chunk.len() == self.size {
+                        return Some(Ok(self.take_chunk()));
+                    }
+                }
+                Some(Err(e)) => {
+                    if self.chunk.is_empty() {
+                        return Some(Err(e));
+                    }
+                    self.pending_err = Some(e);
+                    return Some(Ok(self.take_chunk()));
+                }
+                None => {
+                    if self.chunk.is_empty() {
+                        return None;
+                    }
+                    return Some(Ok(self.take_chunk()));
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // every item of the underlying iterator produces at most one chunk or error
+        let (lo, hi) = self.iter.size_hint();
+        let buffered =
+            usize::from(self.pending_err.is_some()) + usize::from(!self.chunk.is_empty());
+        let hint_inf = if lo > 0 || buffered > 0 { 1 } else { 0 };
+        (hint_inf, hi.and_then(|hi| hi.checked_add(buffered)))
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ChunkByErr<I, O, E> {
+    iter: I,
+    chunk: Vec<O>,
+    pending_err: Option<E>,
+}
+
+impl<I, O, E> Iterator for ChunkByErr<I, O, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<Vec<O>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(e) = self.pending_err.take() {
+            return Some(Err(e));
+        }
+
+        loop {
+            match self.iter.next() {
+                Some(Ok(x)) => self.chunk.push(x),
+                Some(Err(e)) => {
+                    if self.chunk.is_empty() {
+                        return Some(Err(e));
+                    }
+                    self.pending_err = Some(e);
+                    return Some(Ok(mem::take(&mut self.chunk)));
+                }
+                None => {
+                    if self.chunk.is_empty() {
+                        return None;
+                    }
+                    return Some(Ok(mem::take(&mut self.chunk)));
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // every item of the underlying iterator produces at most one chunk or error
+        let (lo, hi) = self.iter.size_hint();
+        let buffered =
+            usize::from(self.pending_err.is_some()) + usize::from(!self.chunk.is_empty());
+        let hint_inf = if lo > 0 || buffered > 0 { 1 } else { 0 };
+        (hint_inf, hi.and_then(|hi| hi.checked_add(buffered)))
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct WindowsOk<I, O> {
+    iter: I,
+    size: usize,
+    window: VecDeque<O>,
+}
+
+impl<I, O, E> Iterator for WindowsOk<I, O>
+where
+    I: Iterator<Item = Result<O, E>>,
+    O: Clone,
+{
+    type Item = Result<Vec<O>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(x) => {
+                    if self.window.len() == self.size {
+                        self.window.pop_front();
+                    }
+                    self.window.push_back(x);
+                    if self.window.len() == self.size {
+                        return Some(Ok(self.window.iter().cloned().collect()));
+                    }
+                }
+                Err(e) => {
+                    self.window.clear();
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct BatchErrors<I: Iterator, F> {
+    iter: Peekable<I>,
+    combine: F,
+}
+
+impl<I, F> Clone for BatchErrors<I, F>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        BatchErrors {
+            iter: self.iter.clone(),
+            combine: self.combine.clone(),
+        }
+    }
+}
+
+impl<I, O, E, A, F> Iterator for BatchErrors<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(Vec<E>) -> A,
+{
+    type Item = Result<O, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.iter.next()? {
+            Ok(x) => return Some(Ok(x)),
+            Err(e) => e,
+        };
+
+        let mut errors = Vec::new();
+        errors.push(first);
+        while let Some(&Err(_)) = self.iter.peek() {
+            if let Some(Err(e)) = self.iter.next() {
+                errors.push(e);
+            }
+        }
+        Some(Err((self.combine)(errors)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (lo.min(1), hi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_ok_trailing_partial_chunk() {
+        let chunks: Vec<_> = vec![Ok::<_, &str>(1), Ok(2), Ok(3), Ok(4), Ok(5)]
+            .into_iter()
+            .chunk_ok(2)
+            .collect();
+
+        assert_eq!(chunks, [Ok(vec![1, 2]), Ok(vec![3, 4]), Ok(vec![5])]);
+    }
+
+    #[test]
+    fn test_chunk_ok_error_mid_chunk() {
+        let mut chunks = vec![Ok(1), Ok(2), Err("a"), Err("b"), Ok(3)]
+            .into_iter()
+            .chunk_ok(3);
+
+        assert_eq!(chunks.next(), Some(Ok(vec![1, 2])));
+        assert_eq!(chunks.size_hint(), (1, Some(3)));
+        assert_eq!(chunks.next(), Some(Err("a")));
+        assert_eq!(chunks.next(), Some(Err("b")));
+        assert_eq!(chunks.next(), Some(Ok(vec![3])));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn test_chunk_ok_zero_size() {
+        let _ = vec![Ok::<usize, &str>(1)].into_iter().chunk_ok(0);
+    }
+
+    #[test]
+    fn test_windows_ok_reset_by_error() {
+        let windows: Vec<_> = vec![Ok(1), Ok(2), Err("a"), Ok(3), Ok(4), Ok(5), Ok(6)]
+            .into_iter()
+            .windows_ok(3)
+            .collect();
+
+        assert_eq!(windows, [Err("a"), Ok(vec![3, 4, 5]), Ok(vec![4, 5, 6])]);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn test_windows_ok_zero_size() {
+        let _ = vec![Ok::<usize, &str>(1)].into_iter().windows_ok(0);
+    }
+
+    #[test]
+    fn test_batch_errors_consecutive() {
+        let mut combined = Vec::new();
+        let batched: Vec<_> = vec![Err("a"), Err("b"), Ok(1), Ok(2), Err("c"), Err("d")]
+            .into_iter()
+            .batch_errors(|errors| {
+                combined.push(errors.len());
+                errors.concat()
+            })
+            .collect();
+
+        assert_eq!(
+            batched,
+            [Err("ab".to_string()), Ok(1), Ok(2), Err("cd".to_string())]
+        );
+        assert_eq!(combined, [2, 2]);
+    }
+
+    #[test]
+    fn test_chunk_by_err_segments() {
+        let mut segments = vec![Err("a"), Ok(1), Ok(2), Ok(3), Err("b"), Ok(4)]
+            .into_iter()
+            .chunk_by_err();
+
+        assert_eq!(segments.next(), Some(Err("a")));
+        assert_eq!(segments.next(), Some(Ok(vec![1, 2, 3])));
+        assert_eq!(segments.size_hint(), (1, Some(2)));
+        assert_eq!(segments.next(), Some(Err("b")));
+        assert_eq!(segments.next(), Some(Ok(vec![4])));
+        assert_eq!(segments.next(), None);
+    }
+}
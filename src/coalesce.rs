@@ -0,0 +1,142 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<T, E>>` to merge the adjacent `Ok` values.
+pub trait Coalesce<T, E>: Sized {
+    /// Merge the adjacent `Ok` values with the function, leaving all `Err` as is.
+    ///
+    /// The accumulated value `a` and the next `Ok` value `b` are passed to `f(a, b)`:
+    /// - `Ok(merged)` becomes the new accumulated value;
+    /// - `Err((a, b))` means they could not be merged: `a` is yielded
+    ///   and the accumulation restarts with `b`.
+    ///
+    /// An `Err` from the underlying iterator flushes the accumulated value:
+    /// it is yielded (as an `Ok`) right before that error,
+    /// so the values on both sides of an error are never merged.
+    ///
+    /// ```
+    /// use resiter::coalesce::Coalesce;
+    ///
+    /// let sums: Vec<_> = vec![Ok(1), Ok(1), Ok(3), Ok(5), Ok(5), Err("a"), Ok(5)]
+    ///     .into_iter()
+    ///     .coalesce_ok(|a, b| if a == b { Ok(a + b) } else { Err((a, b)) })
+    ///     .collect();
+    ///
+    /// assert_eq!(sums, [Ok(2), Ok(3), Ok(10), Err("a"), Ok(5)]);
+    /// ```
+    fn coalesce_ok<F>(self, _: F) -> CoalesceOk<Self, T, E, F>
+    where
+        F: FnMut(T, T) -> Result<T, (T, T)>;
+}
+
+impl<I, T, E> Coalesce<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>> + Sized,
+{
+    #[inline]
+    fn coalesce_ok<F>(self, f: F) -> CoalesceOk<Self, T, E, F>
+    where
+        F: FnMut(T, T) -> Result<T, (T, T)>,
+    {
+        CoalesceOk {
+            iter: self,
+            f,
+            acc: None,
+            pending_err: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct CoalesceOk<I, T, E, F> {
+    iter: I,
+    f: F,
+    acc: Option<T>,
+    pending_err: Option<E>,
+}
+
+impl<I, T, E, F> Iterator for CoalesceOk<I, T, E, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(T, T) -> Result<T, (T, T)>,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(e) = self.pending_err.take() {
+            return Some(Err(e));
+        }
+
+        loop {
+            match self.iter.next() {
+                Some(Ok(b)) => match self.acc.take() {
+                    None => self.acc = Some(b),
+                    Some(a) => match (self.f)(a, b) {
+                        Ok(merged) => self.acc = Some(merged),
+                        Err((a, b)) => {
+                            self.acc = Some(b);
+                            return Some(Ok(a));
+                        }
+                    },
+                },
+                Some(Err(e)) => {
+                    return match self.acc.take() {
+                        Some(a) => {
+                            self.pending_err = Some(e);
+                            Some(Ok(a))
+                        }
+                        None => Some(Err(e)),
+                    };
+                }
+                None => return self.acc.take().map(Ok),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = usize::from(self.acc.is_some()) + usize::from(self.pending_err.is_some());
+        let (lo, hi) = self.iter.size_hint();
+        let lo = if lo > 0 || buffered > 0 { 1 } else { 0 };
+        (lo, hi.and_then(|hi| hi.checked_add(buffered)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coalesce_ok_flush_on_error() {
+        let merged: Vec<_> = vec![Ok("a"), Ok("b"), Err(1), Err(2), Ok("c"), Ok("d")]
+            .into_iter()
+            .map(|r| r.map(String::from))
+            .coalesce_ok(|a, b| Ok(a + &b))
+            .collect();
+
+        assert_eq!(
+            merged,
+            [Ok("ab".to_owned()), Err(1), Err(2), Ok("cd".to_owned())]
+        );
+    }
+
+    #[test]
+    fn test_coalesce_ok_hint() {
+        let mut iter = vec![Ok(1), Ok(2), Err("a")]
+            .into_iter()
+            .coalesce_ok(|a, b| Ok(a + b));
+
+        assert_eq!(iter.size_hint(), (1, Some(3)));
+        assert_eq!(iter.next(), Some(Ok(3)));
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        assert_eq!(iter.next(), Some(Err("a")));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+
+        let mut infinite = std::iter::repeat(Ok::<_, ()>(1)).coalesce_ok(|a, b| Err((a, b)));
+        assert_eq!(infinite.next(), Some(Ok(1)));
+        assert_eq!(infinite.size_hint(), (1, None));
+    }
+}
@@ -0,0 +1,841 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#[cfg(not(test))]
+use core::iter::{self, FromIterator};
+#[cfg(test)]
+use std::iter::{self, FromIterator};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+use util::try_process_oks;
+
+/// The value of one of the two types, used to classify the items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+/// Extension trait for `Iterator<Item = Result<T, E>>` to collect both `Ok` and `Err` values.
+#[cfg(feature = "alloc")]
+pub trait CollectResult<T, E>: Sized {
+    /// Drain the whole iterator, collecting all the `Ok` values into the first `Vec`
+    /// and all the `Err` values into the second one.
+    ///
+    /// The relative order of the items is preserved in both of the `Vec`s.
+    ///
+    /// Unlike the combination of [`GetOks::oks`][crate::oks::GetOks::oks]
+    /// and [`GetErrors::errors`][crate::errors::GetErrors::errors],
+    /// the iterator is processed in a single pass.
+    ///
+    /// ```
+    /// use resiter::collect::CollectResult;
+    /// use std::str::FromStr;
+    ///
+    /// let (oks, errors) = ["1", "2", "a", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|_| txt))
+    ///     .partition_results();
+    ///
+    /// assert_eq!(oks, vec![1, 2, 4]);
+    /// assert_eq!(errors, vec![&"a", &"b"]);
+    /// ```
+    fn partition_results(self) -> (Vec<T>, Vec<E>);
+
+    /// Drain the whole iterator, collecting all the `Ok` values into the first `Vec`
+    /// and classifying all the `Err` values with the function into the second
+    /// ([`Either::Left`]) or the third ([`Either::Right`]) one.
+    ///
+    /// The relative order of the items is preserved in all of the `Vec`s.
+    ///
+    /// ```
+    /// use resiter::collect::{CollectResult, Either};
+    ///
+    /// let (oks, recoverable, fatal) = vec![Ok(1), Err("timeout"), Ok(2), Err("corrupted")]
+    ///     .into_iter()
+    ///     .partition_map_result(|e| {
+    ///         if e == "timeout" {
+    ///             Either::Left(e)
+    ///         } else {
+    ///             Either::Right(e.len())
+    ///         }
+    ///     });
+    ///
+    /// assert_eq!(oks, [1, 2]);
+    /// assert_eq!(recoverable, ["timeout"]);
+    /// assert_eq!(fatal, [9]);
+    /// ```
+    fn partition_map_result<F, A, B>(self, f: F) -> (Vec<T>, Vec<A>, Vec<B>)
+    where
+        F: FnMut(E) -> Either<A, B>;
+
+    /// Drain the whole iterator, dropping all the `Ok` values
+    /// and grouping all the `Err` values by the key produced with the function.
+    ///
+    /// The relative order of the errors is preserved in every group.
+    ///
+    /// ```
+    /// use resiter::collect::CollectResult;
+    ///
+    /// let groups = vec![Ok(1), Err("io: eof"), Err("parse: nan"), Ok(2), Err("io: closed")]
+    ///     .into_iter()
+    ///     .group_errors_by(|e| e.split(':').next().unwrap().to_owned());
+    ///
+    /// assert_eq!(groups.len(), 2);
+    /// assert_eq!(groups["io"], ["io: eof", "io: closed"]);
+    /// assert_eq!(groups["parse"], ["parse: nan"]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn group_errors_by<K, F>(self, f: F) -> HashMap<K, Vec<E>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&E) -> K;
+
+    /// Collect the `Ok` values into any [`FromIterator`] container
+    /// and the `Err` values into a `Vec`, stopping as soon as `max_errors` errors are collected.
+    ///
+    /// The iterator is not advanced past the last collected error,
+    /// so if the method is called on [`by_ref`](Iterator::by_ref),
+    /// the rest of the items are left unconsumed.
+    /// With the `max_errors` of 0 no items are consumed at all.
+    ///
+    /// ```
+    /// use resiter::collect::CollectResult;
+    ///
+    /// let mut iter = vec![Ok(1), Err("a"), Ok(2), Err("b"), Ok(3), Err("c")].into_iter();
+    ///
+    /// let (oks, errors) = iter.by_ref().collect_with_error_limit::<Vec<_>>(2);
+    /// assert_eq!(oks, [1, 2]);
+    /// assert_eq!(errors, ["a", "b"]);
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// ```
+    fn collect_with_error_limit<C>(self, max_errors: usize) -> (C, Vec<E>)
+    where
+        C: FromIterator<T>;
+
+    /// Drain the whole iterator, collecting all the `Ok` values into any [`FromIterator`]
+    /// container if there are no errors, or all the `Err` values otherwise.
+    ///
+    /// Unlike the `collect::<Result<C, E>>()` which stops on the first [`Err`],
+    /// all the errors are reported. Since it is not known in advance whether an error appears,
+    /// all the `Ok` values are kept in the container until the end of the iteration,
+    /// so the memory is spent on them even if they are discarded in the end.
+    ///
+    /// ```
+    /// use resiter::collect::CollectResult;
+    /// use std::str::FromStr;
+    ///
+    /// let parsed = ["1", "2", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|_| txt))
+    ///     .collect_all_or_errors::<Vec<_>>();
+    /// assert_eq!(parsed, Ok(vec![1, 2, 3]));
+    ///
+    /// let parsed = ["1", "a", "3", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|_| txt))
+    ///     .collect_all_or_errors::<Vec<_>>();
+    /// assert_eq!(parsed, Err(vec![&"a", &"b"]));
+    /// ```
+    fn collect_all_or_errors<C>(self) -> Result<C, Vec<E>>
+    where
+        C: FromIterator<T>;
+}
+
+#[cfg(feature = "alloc")]
+impl<I, T, E> CollectResult<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    fn partition_results(self) -> (Vec<T>, Vec<E>) {
+        // errors are expected to be rare, so only reserve the space for the `Ok` values
+        let mut oks = Vec::with_capacity(self.size_hint().0);
+        let mut errors = Vec::new();
+        for res in self {
+            match res {
+                Ok(o) => oks.push(o),
+                Err(e) => errors.push(e),
+            }
+        }
+        (oks, errors)
+    }
+
+    fn partition_map_result<F, A, B>(self, mut f: F) -> (Vec<T>, Vec<A>, Vec<B>)
+    where
+        F: FnMut(E) -> Either<A, B>,
+    {
+        let mut oks = Vec::with_capacity(self.size_hint().0);
+        let mut lefts = Vec::new();
+        let mut rights = Vec::new();
+        for res in self {
+            match res.map_err(&mut f) {
+                Ok(o) => oks.push(o),
+                Err(Either::Left(a)) => lefts.push(a),
+                Err(Either::Right(b)) => rights.push(b),
+            }
+        }
+        (oks, lefts, rights)
+    }
+
+    #[cfg(feature = "std")]
+    fn group_errors_by<K, F>(self, mut f: F) -> HashMap<K, Vec<E>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&E) -> K,
+    {
+        let mut groups = HashMap::new();
+        for e in self.filter_map(Result::err) {
+            groups.entry(f(&e)).or_insert_with(Vec::new).push(e);
+        }
+        groups
+    }
+
+    fn collect_with_error_limit<C>(mut self, max_errors: usize) -> (C, Vec<E>)
+    where
+        C: FromIterator<T>,
+    {
+        let mut errors = Vec::new();
+        let oks = iter::from_fn(|| {
+            while errors.len() < max_errors {
+                match self.next()? {
+                    Ok(o) => return Some(o),
+                    Err(e) => errors.push(e),
+                }
+            }
+            None
+        })
+        .collect();
+        (oks, errors)
+    }
+
+    fn collect_all_or_errors<C>(mut self) -> Result<C, Vec<E>>
+    where
+        C: FromIterator<T>,
+    {
+        let mut errors = Vec::new();
+        let oks = iter::from_fn(|| loop {
+            match self.next()? {
+                Ok(o) => return Some(o),
+                Err(e) => errors.push(e),
+            }
+        })
+        .collect();
+
+        if errors.is_empty() {
+            Ok(oks)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Extension trait for `Iterator<Item = Result<(A, B), E>>` to collect the pairs
+/// of `Ok` values and the `Err` values separately.
+#[cfg(feature = "alloc")]
+pub trait UnzipResults<A, B, E>: Sized {
+    /// Drain the whole iterator, [unzipping](Iterator::unzip) all the `Ok` pairs
+    /// into the first two `Vec`s and collecting all the `Err` values into the third one.
+    ///
+    /// The relative order of the items is preserved in all of the `Vec`s.
+    ///
+    /// ```
+    /// use resiter::collect::UnzipResults;
+    ///
+    /// let (names, ages, errors) = ["alice:30", "bob", "carol:25"]
+    ///     .iter()
+    ///     .map(|txt| {
+    ///         let mut parts = txt.split(':');
+    ///         match (parts.next(), parts.next()) {
+    ///             (Some(name), Some(age)) => Ok((name, age.parse::<u32>().unwrap())),
+    ///             _ => Err(txt),
+    ///         }
+    ///     })
+    ///     .unzip_results();
+    ///
+    /// assert_eq!(names, ["alice", "carol"]);
+    /// assert_eq!(ages, [30, 25]);
+    /// assert_eq!(errors, [&"bob"]);
+    /// ```
+    fn unzip_results(self) -> (Vec<A>, Vec<B>, Vec<E>);
+}
+
+#[cfg(feature = "alloc")]
+impl<I, A, B, E> UnzipResults<A, B, E> for I
+where
+    I: Iterator<Item = Result<(A, B), E>>,
+{
+    fn unzip_results(self) -> (Vec<A>, Vec<B>, Vec<E>) {
+        // the same as for `partition_results`, only the `Ok` values get the space reserved
+        let capacity = self.size_hint().0;
+        let mut lefts = Vec::with_capacity(capacity);
+        let mut rights = Vec::with_capacity(capacity);
+        let mut errors = Vec::new();
+        for res in self {
+            match res {
+                Ok((a, b)) => {
+                    lefts.push(a);
+                    rights.push(b);
+                }
+                Err(e) => errors.push(e),
+            }
+        }
+        (lefts, rights, errors)
+    }
+}
+
+/// Extension trait for `Iterator<Item = Result<T, E>>` to collect the `Ok` values
+/// failing on the first `Err`.
+pub trait TryCollect<T, E>: Sized {
+    /// Collect all the `Ok` values into any [`FromIterator`] container,
+    /// stopping on the first [`Err`] and returning it instead.
+    ///
+    /// This is the same as `collect::<Result<C, E>>()`, but could be chained
+    /// without the type annotations of the `Result` itself.
+    ///
+    /// The iterator is only advanced until the first [`Err`],
+    /// so if the method is called on [`by_ref`](Iterator::by_ref),
+    /// the rest of the items are left unconsumed.
+    ///
+    /// ```
+    /// use resiter::collect::TryCollect;
+    /// use resiter::map::Map;
+    /// use std::str::FromStr;
+    ///
+    /// let doubles = ["1", "2", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .map_ok(|i| 2 * i)
+    ///     .collect_ok_or_first_err::<Vec<_>>();
+    /// assert_eq!(doubles, Ok(vec![2, 4, 6]));
+    ///
+    /// let mut iter = vec![Ok(1), Err("error1"), Ok(3), Err("error2")].into_iter();
+    /// let collected = iter.by_ref().collect_ok_or_first_err::<Vec<_>>();
+    /// assert_eq!(collected, Err("error1"));
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// ```
+    fn collect_ok_or_first_err<C>(self) -> Result<C, E>
+    where
+        C: FromIterator<T>;
+
+    /// Collect the leading `Ok` values into any [`FromIterator`] container
+    /// until the first [`Err`], returning both the collected prefix and that error
+    /// (or `None` if the iterator ended without errors).
+    ///
+    /// The iterator is left right after the first [`Err`]:
+    /// the next call to [`next`](Iterator::next) returns the item following the error.
+    ///
+    /// ```
+    /// use resiter::collect::TryCollect;
+    ///
+    /// let mut iter = vec![Ok(1), Ok(2), Err("error1"), Ok(3), Err("error2")].into_iter();
+    ///
+    /// let (prefix, err) = iter.split_at_first_err::<Vec<_>>();
+    /// assert_eq!(prefix, [1, 2]);
+    /// assert_eq!(err, Some("error1"));
+    ///
+    /// let (prefix, err) = iter.split_at_first_err::<Vec<_>>();
+    /// assert_eq!(prefix, [3]);
+    /// assert_eq!(err, Some("error2"));
+    ///
+    /// let (prefix, err) = iter.split_at_first_err::<Vec<_>>();
+    /// assert!(prefix.is_empty());
+    /// assert_eq!(err, None);
+    /// ```
+    fn split_at_first_err<C>(&mut self) -> (C, Option<E>)
+    where
+        C: FromIterator<T>;
+
+    /// Partition the `Ok` values into two containers with the predicate,
+    /// stopping on the first [`Err`] and returning it instead.
+    ///
+    /// Unlike [`partition_results`](CollectResult::partition_results),
+    /// which keeps all the errors, any error is considered fatal here:
+    /// the values already partitioned are dropped, and the iterator is left
+    /// right after the [`Err`].
+    /// The values for which the predicate returns `true` go to the first container.
+    ///
+    /// ```
+    /// use resiter::collect::TryCollect;
+    ///
+    /// let parts = vec![Ok::<_, &str>(1), Ok(2), Ok(3), Ok(4)]
+    ///     .into_iter()
+    ///     .try_partition::<Vec<_>, Vec<_>, _>(|i| i % 2 == 0);
+    /// assert_eq!(parts, Ok((vec![2, 4], vec![1, 3])));
+    ///
+    /// let mut iter = vec![Ok(1), Err("invalid"), Ok(3)].into_iter();
+    /// let parts = iter.try_partition::<Vec<_>, Vec<_>, _>(|i| i % 2 == 0);
+    /// assert_eq!(parts, Err("invalid"));
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// ```
+    fn try_partition<A, B, P>(&mut self, pred: P) -> Result<(A, B), E>
+    where
+        A: Default + Extend<T>,
+        B: Default + Extend<T>,
+        P: FnMut(&T) -> bool;
+
+    /// Extend the existing container with the `Ok` values,
+    /// stopping on the first [`Err`] and returning it instead.
+    ///
+    /// This is the same as [`collect_ok_or_first_err`](TryCollect::collect_ok_or_first_err),
+    /// but allows to reuse the already allocated container, e.g. to accumulate
+    /// the values from multiple iterators.
+    ///
+    /// On error, the values pushed before it are left in the `target`.
+    ///
+    /// ```
+    /// use resiter::collect::TryCollect;
+    ///
+    /// let mut all = Vec::with_capacity(8);
+    ///
+    /// assert_eq!(vec![Ok::<_, &str>(1), Ok(2)].into_iter().extend_ok(&mut all), Ok(()));
+    /// assert_eq!(vec![Ok(3), Err("a"), Ok(4)].into_iter().extend_ok(&mut all), Err("a"));
+    /// assert_eq!(all, [1, 2, 3]);
+    /// ```
+    fn extend_ok<C>(self, target: &mut C) -> Result<(), E>
+    where
+        C: Extend<T>;
+
+    /// Concatenate all the `Ok` values into a `String`,
+    /// stopping on the first [`Err`] and returning it instead.
+    ///
+    /// This is the same as
+    /// [`collect_ok_or_first_err::<String>`](TryCollect::collect_ok_or_first_err),
+    /// so it works for any item type a `String` could be collected from:
+    /// `char`, `&str`, `String` and so on.
+    ///
+    /// ```
+    /// use resiter::collect::TryCollect;
+    ///
+    /// let decoded = vec![Ok('a'), Ok('b'), Ok('c')].into_iter().collect_string_ok();
+    /// assert_eq!(decoded, Ok::<_, ()>("abc".to_owned()));
+    ///
+    /// let decoded = vec![Ok('a'), Err(0xff), Ok('c')].into_iter().collect_string_ok();
+    /// assert_eq!(decoded, Err(0xff));
+    /// ```
+    ///
+    /// ```
+    /// use resiter::collect::TryCollect;
+    ///
+    /// let joined = vec![Ok("res"), Ok("iter")].into_iter().collect_string_ok();
+    /// assert_eq!(joined, Ok::<_, ()>("resiter".to_owned()));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn collect_string_ok(self) -> Result<String, E>
+    where
+        String: FromIterator<T>;
+
+    /// Collect all the `Ok` key-value pairs into a [`HashMap`],
+    /// stopping on the first [`Err`] and returning it instead.
+    ///
+    /// Same as for the [`HashMap::insert`], if a key is duplicated,
+    /// the last value for it wins.
+    ///
+    /// ```
+    /// use resiter::collect::TryCollect;
+    ///
+    /// let ages = vec![Ok::<_, &str>(("alice", 30)), Ok(("bob", 25)), Ok(("alice", 31))]
+    ///     .into_iter()
+    ///     .collect_map_ok()
+    ///     .unwrap();
+    /// assert_eq!(ages.len(), 2);
+    /// assert_eq!(ages["alice"], 31);
+    /// ```
+    ///
+    /// ```
+    /// use resiter::collect::TryCollect;
+    ///
+    /// let mut iter = vec![Ok(("alice", 30)), Err("no age"), Ok(("bob", 25))].into_iter();
+    /// assert_eq!(iter.by_ref().collect_map_ok(), Err("no age"));
+    /// assert_eq!(iter.next(), Some(Ok(("bob", 25))));
+    /// ```
+    #[cfg(feature = "std")]
+    fn collect_map_ok<K, V>(self) -> Result<HashMap<K, V>, E>
+    where
+        K: Eq + Hash,
+        HashMap<K, V>: FromIterator<T>;
+
+    /// Choose `k` random `Ok` values with the [reservoir sampling] in a single pass,
+    /// keeping no more than `k` values in memory at once.
+    ///
+    /// The sampling fails fast: it stops on the first [`Err`] and returns it,
+    /// rather than skipping it, so no (partial) sample is returned in that case.
+    /// If there are no more than `k` of `Ok` values, all of them are returned
+    /// in their original order.
+    ///
+    /// [reservoir sampling]: https://en.wikipedia.org/wiki/Reservoir_sampling
+    ///
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate rand_chacha;
+    /// use rand::SeedableRng;
+    /// use rand_chacha::ChaCha8Rng;
+    /// use resiter::collect::TryCollect;
+    ///
+    /// let mut rng = ChaCha8Rng::seed_from_u64(42);
+    ///
+    /// let sample = (0..1000)
+    ///     .map(Ok::<_, &str>)
+    ///     .reservoir_sample_ok(3, &mut rng)
+    ///     .unwrap();
+    /// assert_eq!(sample.len(), 3);
+    ///
+    /// let sample = vec![Ok(1), Ok(2), Err("a"), Ok(3)]
+    ///     .into_iter()
+    ///     .reservoir_sample_ok(3, &mut rng);
+    /// assert_eq!(sample, Err("a"));
+    /// ```
+    #[cfg(feature = "rand")]
+    fn reservoir_sample_ok<R>(self, k: usize, rng: &mut R) -> Result<Vec<T>, E>
+    where
+        R: Rng + ?Sized;
+
+    /// Same as the [`reservoir_sample_ok`](TryCollect::reservoir_sample_ok),
+    /// but with any source of randomness instead of the [`rand`](https://docs.rs/rand) crate.
+    ///
+    /// The `random_below(n)` function should return a uniformly distributed index in `0..n`.
+    ///
+    /// ```
+    /// use resiter::collect::TryCollect;
+    ///
+    /// // a deterministic stand-in for a real random numbers generator
+    /// let mut state = 42_usize;
+    /// let random_below = |n: usize| {
+    ///     state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+    ///     (state >> 16) % n
+    /// };
+    ///
+    /// let sample = (0..1000)
+    ///     .map(Ok::<_, &str>)
+    ///     .reservoir_sample_ok_with(3, random_below)
+    ///     .unwrap();
+    /// assert_eq!(sample.len(), 3);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn reservoir_sample_ok_with<R>(self, k: usize, random_below: R) -> Result<Vec<T>, E>
+    where
+        R: FnMut(usize) -> usize;
+}
+
+impl<I, T, E> TryCollect<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    #[inline]
+    fn collect_ok_or_first_err<C>(self) -> Result<C, E>
+    where
+        C: FromIterator<T>,
+    {
+        try_process_oks(self, |oks| oks.collect())
+    }
+
+    fn split_at_first_err<C>(&mut self) -> (C, Option<E>)
+    where
+        C: FromIterator<T>,
+    {
+        let mut error = None;
+        let prefix = iter::from_fn(|| match self.next()? {
+            Ok(t) => Some(t),
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        })
+        .collect();
+        (prefix, error)
+    }
+
+    fn try_partition<A, B, P>(&mut self, mut pred: P) -> Result<(A, B), E>
+    where
+        A: Default + Extend<T>,
+        B: Default + Extend<T>,
+        P: FnMut(&T) -> bool,
+    {
+        let mut left = A::default();
+        let mut right = B::default();
+        for res in self {
+            let t = res?;
+            if pred(&t) {
+                left.extend(Some(t));
+            } else {
+                right.extend(Some(t));
+            }
+        }
+        Ok((left, right))
+    }
+
+    #[inline]
+    fn extend_ok<C>(self, target: &mut C) -> Result<(), E>
+    where
+        C: Extend<T>,
+    {
+        try_process_oks(self, |oks| target.extend(oks))
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn collect_string_ok(self) -> Result<String, E>
+    where
+        String: FromIterator<T>,
+    {
+        self.collect_ok_or_first_err()
+    }
+
+    #[inline]
+    #[cfg(feature = "std")]
+    fn collect_map_ok<K, V>(self) -> Result<HashMap<K, V>, E>
+    where
+        K: Eq + Hash,
+        HashMap<K, V>: FromIterator<T>,
+    {
+        self.collect_ok_or_first_err()
+    }
+
+    #[inline]
+    #[cfg(feature = "rand")]
+    fn reservoir_sample_ok<R>(self, k: usize, rng: &mut R) -> Result<Vec<T>, E>
+    where
+        R: Rng + ?Sized,
+    {
+        self.reservoir_sample_ok_with(k, |n| rng.gen_range(0..n))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn reservoir_sample_ok_with<R>(self, k: usize, mut random_below: R) -> Result<Vec<T>, E>
+    where
+        R: FnMut(usize) -> usize,
+    {
+        let mut sample = Vec::new();
+        let mut seen = 0;
+        for res in self {
+            let x = res?;
+            seen += 1;
+            if sample.len() < k {
+                sample.push(x);
+            } else if let Some(slot) = sample.get_mut(random_below(seen)) {
+                *slot = x;
+            }
+        }
+        Ok(sample)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_ok_or_first_err_empty() {
+        let collected = Vec::<Result<usize, &str>>::new()
+            .into_iter()
+            .collect_ok_or_first_err::<Vec<_>>();
+
+        assert_eq!(collected, Ok(vec![]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_unzip_results_mixed() {
+        let (lefts, rights, errors) =
+            vec![Ok((1, 'a')), Err("x"), Ok((2, 'b')), Err("y"), Ok((3, 'c'))]
+                .into_iter()
+                .unzip_results();
+
+        assert_eq!(lefts, [1, 2, 3]);
+        assert_eq!(rights, ['a', 'b', 'c']);
+        assert_eq!(errors, ["x", "y"]);
+    }
+
+    #[test]
+    fn test_split_at_first_err_residual() {
+        let mut iter = vec![Ok(1), Ok(2), Err("a"), Ok(3), Ok(4)].into_iter();
+
+        let (prefix, err) = iter.split_at_first_err::<Vec<_>>();
+        assert_eq!(prefix, [1, 2]);
+        assert_eq!(err, Some("a"));
+
+        assert_eq!(iter.collect::<Vec<_>>(), [Ok(3), Ok(4)]);
+    }
+
+    #[test]
+    fn test_split_at_first_err_error_first() {
+        let mut iter = vec![Err("a"), Ok(1)].into_iter();
+
+        let (prefix, err) = iter.split_at_first_err::<Vec<_>>();
+        assert!(prefix.is_empty());
+        assert_eq!(err, Some("a"));
+        assert_eq!(iter.next(), Some(Ok(1)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_partition_map_result_even_odd_codes() {
+        let (oks, even, odd) = vec![Ok("a"), Err(1), Err(2), Ok("b"), Err(4), Err(7)]
+            .into_iter()
+            .partition_map_result(|code| {
+                if code % 2 == 0 {
+                    Either::Left(code)
+                } else {
+                    Either::Right(code)
+                }
+            });
+
+        assert_eq!(oks, ["a", "b"]);
+        assert_eq!(even, [2, 4]);
+        assert_eq!(odd, [1, 7]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_group_errors_by_first_char() {
+        let groups = vec![Err("apple"), Ok(1), Err("banana"), Err("avocado"), Ok(2)]
+            .into_iter()
+            .group_errors_by(|e| e.chars().next());
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[&Some('a')], ["apple", "avocado"]);
+        assert_eq!(groups[&Some('b')], ["banana"]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_collect_with_error_limit() {
+        let mut iter = vec![Err("a"), Ok(1), Err("b"), Err("c"), Ok(2), Err("d")].into_iter();
+
+        let (oks, errors) = iter.by_ref().collect_with_error_limit::<Vec<_>>(2);
+        assert_eq!(oks, [1]);
+        assert_eq!(errors, ["a", "b"]);
+
+        assert_eq!(iter.collect::<Vec<_>>(), [Err("c"), Ok(2), Err("d")]);
+    }
+
+    #[test]
+    fn test_try_partition_aborted_by_error() {
+        use std::collections::BTreeSet;
+
+        let mut iter = vec![Ok(5), Ok(1), Ok(6), Err("a"), Ok(2), Err("b")].into_iter();
+
+        let parts = iter.try_partition::<Vec<_>, BTreeSet<_>, _>(|&i| i > 3);
+        assert_eq!(parts, Err("a"));
+
+        let (big, small) = iter
+            .by_ref()
+            .take(1)
+            .try_partition::<Vec<_>, BTreeSet<_>, _>(|&i| i > 3)
+            .unwrap();
+        assert!(big.is_empty());
+        assert_eq!(small.into_iter().collect::<Vec<_>>(), [2]);
+        assert_eq!(iter.next(), Some(Err("b")));
+    }
+
+    #[test]
+    fn test_extend_ok_reuse_vec() {
+        let mut all = Vec::new();
+
+        let first = vec![Ok(1), Ok(2), Err("a"), Ok(3)];
+        assert_eq!(first.into_iter().extend_ok(&mut all), Err("a"));
+        assert_eq!(all, [1, 2]);
+
+        let second = vec![Ok::<_, &str>(4), Ok(5)];
+        assert_eq!(second.into_iter().extend_ok(&mut all), Ok(()));
+        assert_eq!(all, [1, 2, 4, 5]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_collect_string_ok_owned_strings() {
+        let mut iter = vec![
+            Ok("a".to_owned()),
+            Ok("b".to_owned()),
+            Err(1),
+            Ok("c".to_owned()),
+        ]
+        .into_iter();
+
+        assert_eq!(iter.by_ref().collect_string_ok(), Err(1));
+        assert_eq!(iter.collect_string_ok(), Ok("c".to_owned()));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_reservoir_sample_ok_with_fixed_seed() {
+        fn xorshift(seed: u64) -> impl FnMut(usize) -> usize {
+            let mut state = seed;
+            move |n| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state % n as u64) as usize
+            }
+        }
+
+        let sample = (0..100)
+            .map(Ok::<_, &str>)
+            .reservoir_sample_ok_with(4, xorshift(1))
+            .unwrap();
+        let again = (0..100)
+            .map(Ok::<_, &str>)
+            .reservoir_sample_ok_with(4, xorshift(1))
+            .unwrap();
+        assert_eq!(sample, [94, 25, 16, 52]);
+        assert_eq!(sample, again);
+
+        let all = vec![Ok::<_, &str>(1), Ok(2)]
+            .into_iter()
+            .reservoir_sample_ok_with(4, xorshift(1));
+        assert_eq!(all, Ok(vec![1, 2]));
+
+        let everything = (0..10)
+            .map(Ok::<_, &str>)
+            .reservoir_sample_ok_with(usize::MAX, xorshift(1));
+        assert_eq!(everything, Ok((0..10).collect()));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_reservoir_sample_ok_with_aborted_by_error() {
+        let mut calls = 0;
+        let mut iter = vec![Ok(1), Ok(2), Ok(3), Err("a"), Ok(4)].into_iter();
+
+        let sample = iter.by_ref().reservoir_sample_ok_with(2, |n| {
+            calls += 1;
+            n - 1
+        });
+        assert_eq!(sample, Err("a"));
+        // only the third value was a candidate for the replacement
+        assert_eq!(calls, 1);
+        assert_eq!(iter.next(), Some(Ok(4)));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_reservoir_sample_ok_fixed_seed() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+
+        let sample = (0..100)
+            .map(Ok::<_, &str>)
+            .reservoir_sample_ok(4, &mut ChaCha8Rng::seed_from_u64(1));
+        assert_eq!(sample, Ok(vec![21, 22, 72, 52]));
+
+        let mut iter = vec![Ok(1), Ok(2), Err("a"), Ok(3)].into_iter();
+        let sample = iter
+            .by_ref()
+            .reservoir_sample_ok(1, &mut ChaCha8Rng::seed_from_u64(1));
+        assert_eq!(sample, Err("a"));
+        assert_eq!(iter.next(), Some(Ok(3)));
+    }
+}
@@ -0,0 +1,234 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<T, E>>` to count the `Ok` and `Err` values.
+///
+/// All the methods but [`count_until_err`](Count::count_until_err) always consume
+/// the whole iterator, without any short-circuiting.
+pub trait Count<T, E>: Sized {
+    /// Count all the `Ok` values
+    ///
+    /// ```
+    /// use resiter::count::Count;
+    /// use std::str::FromStr;
+    ///
+    /// let count = ["1", "2", "a", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .count_ok();
+    ///
+    /// assert_eq!(count, 3);
+    /// ```
+    fn count_ok(self) -> usize;
+
+    /// Count all the `Err` values
+    ///
+    /// ```
+    /// use resiter::count::Count;
+    /// use std::str::FromStr;
+    ///
+    /// let count = ["1", "2", "a", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .count_err();
+    ///
+    /// assert_eq!(count, 2);
+    /// ```
+    fn count_err(self) -> usize;
+
+    /// Count both the `Ok` and the `Err` values in a single pass,
+    /// returning the `(oks, errors)` pair.
+    ///
+    /// ```
+    /// use resiter::count::Count;
+    /// use std::str::FromStr;
+    ///
+    /// let counts = ["1", "2", "a", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .count_both();
+    ///
+    /// assert_eq!(counts, (3, 2));
+    /// ```
+    fn count_both(self) -> (usize, usize);
+
+    /// Count the `Ok` values until the first `Err`, returning the count and that error
+    /// (or `None` if the iterator is exhausted without errors).
+    ///
+    /// The iterator is not advanced past the first `Err`.
+    ///
+    /// ```
+    /// use resiter::count::Count;
+    /// use std::str::FromStr;
+    ///
+    /// let mut iter = ["1", "2", "a", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|_| txt));
+    ///
+    /// assert_eq!(iter.count_until_err(), (2, Some(&"a")));
+    /// assert_eq!(iter.count_until_err(), (1, Some(&"b")));
+    /// assert_eq!(iter.count_until_err(), (0, None));
+    /// ```
+    fn count_until_err(&mut self) -> (usize, Option<E>);
+
+    /// Count both the `Ok` and the `Err` values in a single pass,
+    /// also keeping the first and the last errors.
+    ///
+    /// Only the first error is cloned, to be kept along with the following ones.
+    /// If there is a single error, both of the `first_err` and the `last_err` are set to it.
+    ///
+    /// ```
+    /// use resiter::count::{Count, ResultStats};
+    /// use std::str::FromStr;
+    ///
+    /// let stats = ["1", "2", "a", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|_| txt))
+    ///     .result_stats();
+    ///
+    /// assert_eq!(
+    ///     stats,
+    ///     ResultStats {
+    ///         oks: 3,
+    ///         errs: 2,
+    ///         first_err: Some(&"a"),
+    ///         last_err: Some(&"b"),
+    ///     }
+    /// );
+    /// ```
+    fn result_stats(self) -> ResultStats<E>
+    where
+        E: Clone;
+}
+
+/// The summary of an `Iterator<Item = Result<T, E>>` produced by
+/// [`result_stats`](Count::result_stats).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResultStats<E> {
+    /// The number of the `Ok` values
+    pub oks: usize,
+    /// The number of the `Err` values
+    pub errs: usize,
+    /// The first `Err` value, if any
+    pub first_err: Option<E>,
+    /// The last `Err` value, if any
+    pub last_err: Option<E>,
+}
+
+impl<I, T, E> Count<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    #[inline]
+    fn count_ok(self) -> usize {
+        self.filter(Result::is_ok).count()
+    }
+
+    #[inline]
+    fn count_err(self) -> usize {
+        self.filter(Result::is_err).count()
+    }
+
+    #[inline]
+    fn count_both(self) -> (usize, usize) {
+        self.fold((0, 0), |(oks, errors), res| match res {
+            Ok(_) => (oks + 1, errors),
+            Err(_) => (oks, errors + 1),
+        })
+    }
+
+    fn count_until_err(&mut self) -> (usize, Option<E>) {
+        let mut count = 0;
+        for res in self {
+            match res {
+                Ok(_) => count += 1,
+                Err(e) => return (count, Some(e)),
+            }
+        }
+        (count, None)
+    }
+
+    fn result_stats(self) -> ResultStats<E>
+    where
+        E: Clone,
+    {
+        let init = ResultStats {
+            oks: 0,
+            errs: 0,
+            first_err: None,
+            last_err: None,
+        };
+        self.fold(init, |mut stats, res| {
+            match res {
+                Ok(_) => stats.oks += 1,
+                Err(e) => {
+                    stats.errs += 1;
+                    if stats.first_err.is_none() {
+                        stats.first_err = Some(e.clone());
+                    }
+                    stats.last_err = Some(e);
+                }
+            }
+            stats
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_until_err_all_ok() {
+        let mut iter = vec![Ok::<_, &str>(1), Ok(2), Ok(3)].into_iter();
+
+        assert_eq!(iter.count_until_err(), (3, None));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_count_until_err_error_first() {
+        let mut iter = vec![Err("a"), Ok(1), Ok(2)].into_iter();
+
+        assert_eq!(iter.count_until_err(), (0, Some("a")));
+        assert_eq!(iter.next(), Some(Ok(1)));
+    }
+
+    #[test]
+    fn test_count_until_err_error_in_the_middle() {
+        let mut iter = vec![Ok(1), Ok(2), Err("a"), Ok(3), Err("b")].into_iter();
+
+        assert_eq!(iter.count_until_err(), (2, Some("a")));
+        assert_eq!(iter.next(), Some(Ok(3)));
+    }
+
+    #[test]
+    fn test_result_stats_mixed() {
+        let stats = vec![Err("a"), Ok(1), Err("b"), Ok(2), Err("c"), Ok(3)]
+            .into_iter()
+            .result_stats();
+
+        assert_eq!(stats.oks, 3);
+        assert_eq!(stats.errs, 3);
+        assert_eq!(stats.first_err, Some("a"));
+        assert_eq!(stats.last_err, Some("c"));
+    }
+
+    #[test]
+    fn test_result_stats_no_errors() {
+        let stats = vec![Ok::<_, &str>(1), Ok(2)].into_iter().result_stats();
+
+        assert_eq!(
+            stats,
+            ResultStats {
+                oks: 2,
+                errs: 0,
+                first_err: None,
+                last_err: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::fmt;
+use std::time::Instant;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to stop the iteration
+/// at some point in time.
+pub trait Deadline<O, E>: Sized {
+    /// Yield all the items as is until the `deadline`, then yield an `Err`
+    /// converted from the [`DeadlineExceeded`] and stop permanently.
+    ///
+    /// The time is checked with [`Instant::now`] on every call of [`next`](Iterator::next),
+    /// before pulling the next item, so an item that takes long to produce
+    /// is still yielded even if the deadline passes in the meantime.
+    /// If the underlying iterator ends before the deadline, no error is yielded.
+    ///
+    /// ```
+    /// use resiter::deadline::{Deadline, DeadlineExceeded};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(60);
+    /// let items: Vec<Result<_, DeadlineExceeded>> = vec![Ok(1), Ok(2)]
+    ///     .into_iter()
+    ///     .until_deadline(deadline)
+    ///     .collect();
+    ///
+    /// assert_eq!(items, [Ok(1), Ok(2)]);
+    /// ```
+    fn until_deadline(self, deadline: Instant) -> UntilDeadline<Self>
+    where
+        E: From<DeadlineExceeded>;
+}
+
+/// The error injected by the [`until_deadline`](Deadline::until_deadline)
+/// when the deadline has passed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DeadlineExceeded;
+
+impl fmt::Display for DeadlineExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the deadline has passed")
+    }
+}
+
+impl std::error::Error for DeadlineExceeded {}
+
+impl<I, O, E> Deadline<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn until_deadline(self, deadline: Instant) -> UntilDeadline<Self>
+    where
+        E: From<DeadlineExceeded>,
+    {
+        UntilDeadline {
+            iter: self,
+            deadline,
+            done: false,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct UntilDeadline<I> {
+    iter: I,
+    deadline: Instant,
+    done: bool,
+}
+
+impl<I, O, E> Iterator for UntilDeadline<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E: From<DeadlineExceeded>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if Instant::now() >= self.deadline {
+            self.done = true;
+            return Some(Err(DeadlineExceeded.into()));
+        }
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (lo, hi) = self.iter.size_hint();
+            (lo.min(1), hi.and_then(|hi| hi.checked_add(1)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_until_deadline_in_the_past() {
+        let mut source = vec![Ok(1), Ok(2)].into_iter();
+        let mut iter = source.by_ref().until_deadline(Instant::now());
+
+        assert_eq!(iter.next(), Some(Err(DeadlineExceeded)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(source.next(), Some(Ok(1)));
+    }
+}
@@ -0,0 +1,286 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to remove the duplicates.
+pub trait Dedup<O, E>: Sized {
+    /// Remove the consecutive `Ok` values that resolve to the same key,
+    /// only keeping the first one of every run.
+    ///
+    /// Every `Err` is yielded as is and acts as a separator:
+    /// it resets the previously seen key, so the `Ok` values on both sides of an error
+    /// are never considered adjacent, even if their keys are equal.
+    ///
+    /// ```
+    /// use resiter::dedup::Dedup;
+    ///
+    /// let deduped: Vec<_> = vec![Ok(1), Ok(11), Ok(2), Err("a"), Ok(12), Ok(3)]
+    ///     .into_iter()
+    ///     .dedup_ok_by_key(|i| i % 10)
+    ///     .collect();
+    ///
+    /// assert_eq!(deduped, [Ok(1), Ok(2), Err("a"), Ok(12), Ok(3)]);
+    /// ```
+    fn dedup_ok_by_key<K, F>(self, _: F) -> DedupOkByKey<Self, K, F>
+    where
+        K: PartialEq,
+        F: FnMut(&O) -> K;
+
+    /// Remove the `Err` values equal to the previously yielded error,
+    /// while yielding every `Ok` value as is.
+    ///
+    /// Only the error channel is considered: the `Ok` values in between
+    /// do not reset the last seen error, so an error repeated after a success
+    /// is still removed. Every yielded error is cloned to be compared
+    /// with the following ones.
+    ///
+    /// ```
+    /// use resiter::dedup::Dedup;
+    ///
+    /// let deduped: Vec<_> = vec![Err("a"), Err("a"), Ok(1), Err("a"), Err("b"), Err("a")]
+    ///     .into_iter()
+    ///     .dedup_errors()
+    ///     .collect();
+    ///
+    /// assert_eq!(deduped, [Err("a"), Ok(1), Err("b"), Err("a")]);
+    /// ```
+    fn dedup_errors(self) -> DedupErrors<Self, E>
+    where
+        E: PartialEq + Clone;
+
+    /// Remove all the `Ok` values that resolve to an already seen key,
+    /// only keeping the first occurrence of every key.
+    ///
+    /// Unlike [`dedup_ok_by_key`](Dedup::dedup_ok_by_key), the duplicates are searched
+    /// over the whole iterator, not only among the adjacent values,
+    /// and the errors do not reset anything, they are just yielded as is.
+    ///
+    /// Every distinct key is stored in a [`HashSet`] for the whole lifetime
+    /// of the iterator, so the memory usage grows with the number of unique keys.
+    ///
+    /// ```
+    /// use resiter::dedup::Dedup;
+    ///
+    /// let unique: Vec<_> = vec![Ok(1), Ok(11), Err("a"), Ok(2), Ok(21), Ok(3)]
+    ///     .into_iter()
+    ///     .cache_ok_by(|i| i % 10)
+    ///     .collect();
+    ///
+    /// assert_eq!(unique, [Ok(1), Err("a"), Ok(2), Ok(3)]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn cache_ok_by<K, F>(self, _: F) -> CacheOkBy<Self, K, F>
+    where
+        K: Eq + Hash,
+        F: FnMut(&O) -> K;
+}
+
+impl<I, O, E> Dedup<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn dedup_ok_by_key<K, F>(self, f: F) -> DedupOkByKey<Self, K, F>
+    where
+        K: PartialEq,
+        F: FnMut(&O) -> K,
+    {
+        DedupOkByKey {
+            iter: self,
+            f,
+            last_key: None,
+        }
+    }
+
+    #[inline]
+    fn dedup_errors(self) -> DedupErrors<Self, E>
+    where
+        E: PartialEq + Clone,
+    {
+        DedupErrors {
+            iter: self,
+            last_err: None,
+        }
+    }
+
+    #[inline]
+    #[cfg(feature = "std")]
+    fn cache_ok_by<K, F>(self, f: F) -> CacheOkBy<Self, K, F>
+    where
+        K: Eq + Hash,
+        F: FnMut(&O) -> K,
+    {
+        CacheOkBy {
+            iter: self,
+            f,
+            seen: HashSet::new(),
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct DedupOkByKey<I, K, F> {
+    iter: I,
+    f: F,
+    last_key: Option<K>,
+}
+
+impl<I, O, E, K, F> Iterator for DedupOkByKey<I, K, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    K: PartialEq,
+    F: FnMut(&O) -> K,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(x) => {
+                    let key = (self.f)(&x);
+                    if self.last_key.as_ref() != Some(&key) {
+                        self.last_key = Some(key);
+                        return Some(Ok(x));
+                    }
+                }
+                Err(e) => {
+                    self.last_key = None;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct DedupErrors<I, E> {
+    iter: I,
+    last_err: Option<E>,
+}
+
+impl<I, O, E> Iterator for DedupErrors<I, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E: PartialEq + Clone,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(x) => return Some(Ok(x)),
+                Err(e) => {
+                    if self.last_err.as_ref() != Some(&e) {
+                        self.last_err = Some(e.clone());
+                        return Some(Err(e));
+                    }
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct CacheOkBy<I, K, F> {
+    iter: I,
+    f: F,
+    seen: HashSet<K>,
+}
+
+#[cfg(feature = "std")]
+impl<I, O, E, K, F> Iterator for CacheOkBy<I, K, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    K: Eq + Hash,
+    F: FnMut(&O) -> K,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(x) => {
+                    if self.seen.insert((self.f)(&x)) {
+                        return Some(Ok(x));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dedup_ok_by_key_collapses_runs() {
+        let deduped: Vec<_> = vec![Ok::<_, &str>(1), Ok(1), Ok(2), Ok(2), Ok(2), Ok(1)]
+            .into_iter()
+            .dedup_ok_by_key(|&i| i)
+            .collect();
+
+        assert_eq!(deduped, [Ok(1), Ok(2), Ok(1)]);
+    }
+
+    #[test]
+    fn test_dedup_ok_by_key_run_broken_by_error() {
+        let deduped: Vec<_> = vec![Ok(1), Ok(1), Err("a"), Ok(1), Err("b"), Err("c"), Ok(1)]
+            .into_iter()
+            .dedup_ok_by_key(|&i| i)
+            .collect();
+
+        assert_eq!(deduped, [Ok(1), Err("a"), Ok(1), Err("b"), Err("c"), Ok(1)]);
+    }
+
+    #[test]
+    fn test_dedup_errors_consecutive() {
+        let deduped: Vec<_> = vec![Ok(1), Err("a"), Err("a"), Err("a"), Ok(2), Err("b")]
+            .into_iter()
+            .dedup_errors()
+            .collect();
+
+        assert_eq!(deduped, [Ok(1), Err("a"), Ok(2), Err("b")]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_cache_ok_by_drops_later_duplicate() {
+        let unique: Vec<_> = vec![Ok("apple"), Ok("banana"), Err(1), Ok("avocado"), Err(2)]
+            .into_iter()
+            .cache_ok_by(|s| s.chars().next())
+            .collect();
+
+        assert_eq!(unique, [Ok("apple"), Ok("banana"), Err(1), Err(2)]);
+    }
+}
@@ -0,0 +1,103 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use alloc::collections::VecDeque;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to postpone the `Err` values.
+pub trait Defer<O, E>: Sized {
+    /// Yield all the `Ok` values as they come, and all the `Err` values
+    /// after the underlying iterator is exhausted, in their original order.
+    ///
+    /// No error is yielded until the whole underlying iterator is consumed,
+    /// and all of the errors are buffered until then,
+    /// so the memory usage grows with the number of errors.
+    ///
+    /// ```
+    /// use resiter::defer::Defer;
+    ///
+    /// let items: Vec<_> = vec![Err("a"), Ok(1), Err("b"), Ok(2)]
+    ///     .into_iter()
+    ///     .defer_errors()
+    ///     .collect();
+    ///
+    /// assert_eq!(items, [Ok(1), Ok(2), Err("a"), Err("b")]);
+    /// ```
+    fn defer_errors(self) -> DeferErrors<Self, E>;
+}
+
+impl<I, O, E> Defer<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn defer_errors(self) -> DeferErrors<Self, E> {
+        DeferErrors {
+            iter: self,
+            errors: VecDeque::new(),
+            exhausted: false,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct DeferErrors<I, E> {
+    iter: I,
+    errors: VecDeque<E>,
+    exhausted: bool,
+}
+
+impl<I, O, E> Iterator for DeferErrors<I, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.exhausted {
+            match self.iter.next() {
+                Some(Ok(x)) => return Some(Ok(x)),
+                Some(Err(e)) => self.errors.push_back(e),
+                None => self.exhausted = true,
+            }
+        }
+        self.errors.pop_front().map(Err)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.errors.len();
+        if self.exhausted {
+            (buffered, Some(buffered))
+        } else {
+            let (lo, hi) = self.iter.size_hint();
+            (
+                lo.saturating_add(buffered),
+                hi.and_then(|hi| hi.checked_add(buffered)),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defer_errors_alternating() {
+        let mut iter = vec![Ok(1), Err("a"), Ok(2), Err("b")]
+            .into_iter()
+            .defer_errors();
+
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.next(), Some(Ok(2)));
+        // one error is buffered and one is left in the source
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next(), Some(Err("a")));
+        assert_eq!(iter.next(), Some(Err("b")));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,95 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to index the items.
+pub trait Enumerate<O, E>: Sized {
+    /// [enumerate](Iterator::enumerate) the `Ok` values while leaving `Err` as is.
+    ///
+    /// The index is only advanced on the `Ok` values, so the successive `Ok`s
+    /// are numbered `0, 1, 2, ...` regardless of the errors in between.
+    /// To count every item, errors included, use the plain [`Iterator::enumerate`].
+    ///
+    /// ```
+    /// use resiter::enumerate::Enumerate;
+    /// use std::str::FromStr;
+    ///
+    /// let numbered: Vec<_> = ["a", "b", "1", "c", "2"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|_| txt))
+    ///     .enumerate_ok()
+    ///     .collect();
+    ///
+    /// assert_eq!(numbered, [Err(&"a"), Err(&"b"), Ok((0, 1)), Err(&"c"), Ok((1, 2))]);
+    /// ```
+    fn enumerate_ok(self) -> EnumerateOk<Self>;
+}
+
+impl<I, O, E> Enumerate<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn enumerate_ok(self) -> EnumerateOk<Self> {
+        EnumerateOk {
+            iter: self,
+            count: 0,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct EnumerateOk<I> {
+    iter: I,
+    count: usize,
+}
+
+impl<I, O, E> Iterator for EnumerateOk<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<(usize, O), E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|r| {
+            r.map(|o| {
+                let i = self.count;
+                self.count += 1;
+                (i, o)
+            })
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enumerate_ok_skips_errors() {
+        let numbered: Vec<_> = vec![Ok('a'), Err(1), Err(2), Ok('b'), Ok('c'), Err(3)]
+            .into_iter()
+            .enumerate_ok()
+            .collect();
+
+        assert_eq!(
+            numbered,
+            [
+                Ok((0, 'a')),
+                Err(1),
+                Err(2),
+                Ok((1, 'b')),
+                Ok((2, 'c')),
+                Err(3)
+            ]
+        );
+    }
+}
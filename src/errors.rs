@@ -19,6 +19,9 @@ pub use util::Process as Errors;
 pub trait GetErrors<T, E>: Sized {
     /// Get all errors from this `Iterator`
     ///
+    /// The returned iterator is lazy and only keeps the upper bound of the
+    /// [`size_hint`](Iterator::size_hint), as any of the items could be an `Ok`.
+    ///
     /// ```
     /// use std::str::FromStr;
     /// use resiter::GetErrors;
@@ -31,7 +34,59 @@ pub trait GetErrors<T, E>: Sized {
     ///
     /// assert_eq!(res.len(), 2);
     /// ```
+    ///
+    /// The returned iterator is [double-ended](DoubleEndedIterator) and
+    /// [fused](core::iter::FusedIterator) whenever the underlying iterator is,
+    /// so the last error could be cheaply retrieved:
+    ///
+    /// ```
+    /// use resiter::GetErrors;
+    ///
+    /// let last = vec![Ok(1), Err("a"), Ok(3), Err("b"), Ok(5)]
+    ///     .into_iter()
+    ///     .errors()
+    ///     .next_back();
+    ///
+    /// assert_eq!(last, Some("b"));
+    /// ```
     fn errors(self) -> FilterMap<Self, fn(Result<T, E>) -> Option<E>>;
+
+    /// Collect all errors from this `Iterator` into a single aggregate,
+    /// or return `None` if there were no errors at all.
+    ///
+    /// The whole iterator is consumed, and all the `Ok` values are dropped.
+    ///
+    /// ```
+    /// use resiter::GetErrors;
+    ///
+    /// let all: Option<Vec<&str>> = vec![Ok(1), Err("a"), Ok(3), Err("b")]
+    ///     .into_iter()
+    ///     .collect_errors();
+    /// assert_eq!(all, Some(vec!["a", "b"]));
+    ///
+    /// let none: Option<Vec<&str>> = vec![Ok(1), Ok(2)].into_iter().collect_errors();
+    /// assert_eq!(none, None);
+    /// ```
+    fn collect_errors<A>(self) -> Option<A>
+    where
+        A: FromIterator<E>;
+
+    /// Get all errors from this `Iterator` along with their positions
+    ///
+    /// The index counts every item (both `Ok` and `Err`),
+    /// so it matches the position of the error in the original iterator.
+    ///
+    /// ```
+    /// use resiter::GetErrors;
+    ///
+    /// let errors: Vec<_> = vec![Ok(1), Ok(2), Err("a"), Ok(4), Err("b")]
+    ///     .into_iter()
+    ///     .errors_with_index()
+    ///     .collect();
+    ///
+    /// assert_eq!(errors, [(2, "a"), (4, "b")]);
+    /// ```
+    fn errors_with_index(self) -> ErrorsWithIndex<Self>;
 }
 
 impl<T, E, I> GetErrors<T, E> for I
@@ -42,4 +97,117 @@ where
     fn errors(self) -> FilterMap<Self, fn(Result<T, E>) -> Option<E>> {
         self.filter_map(GetErr::get_err)
     }
+
+    fn collect_errors<A>(self) -> Option<A>
+    where
+        A: FromIterator<E>,
+    {
+        let mut errors = self.errors().peekable();
+        errors.peek()?;
+        Some(errors.collect())
+    }
+
+    #[inline]
+    fn errors_with_index(self) -> ErrorsWithIndex<Self> {
+        ErrorsWithIndex {
+            iter: self,
+            index: 0,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ErrorsWithIndex<I> {
+    iter: I,
+    index: usize,
+}
+
+impl<I, T, E> Iterator for ErrorsWithIndex<I>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = (usize, E);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let res = self.iter.next()?;
+            let index = self.index;
+            self.index += 1;
+            if let Err(e) = res {
+                return Some((index, e));
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors_double_ended() {
+        let mut errors = vec![Err(1), Ok("a"), Err(2), Err(3), Ok("b"), Err(4), Ok("c")]
+            .into_iter()
+            .errors();
+
+        assert_eq!(errors.next_back(), Some(4));
+        assert_eq!(errors.next(), Some(1));
+        assert_eq!(errors.next_back(), Some(3));
+        assert_eq!(errors.next(), Some(2));
+        assert_eq!(errors.next_back(), None);
+        assert_eq!(errors.next(), None);
+    }
+
+    #[test]
+    fn test_errors_rev() {
+        let errors: Vec<_> = vec![Err(1), Ok("a"), Err(2), Ok("b"), Err(3)]
+            .into_iter()
+            .errors()
+            .rev()
+            .collect();
+
+        assert_eq!(errors, [3, 2, 1]);
+    }
+
+    #[test]
+    fn test_errors_fused() {
+        fn assert_fused<I: FusedIterator>(_: &I) {}
+
+        let mut errors = vec![Err(1), Ok("a")].into_iter().errors();
+        assert_fused(&errors);
+
+        assert_eq!(errors.next(), Some(1));
+        assert_eq!(errors.next(), None);
+        assert_eq!(errors.next(), None);
+    }
+
+    #[test]
+    fn test_errors_with_index_by_ref() {
+        let mut iter = vec![Err("a"), Ok(1), Err("b"), Ok(2), Err("c")].into_iter();
+
+        assert_eq!(iter.by_ref().errors_with_index().nth(1), Some((2, "b")));
+        // the index is counted from the start of the adapter
+        assert_eq!(iter.errors_with_index().next(), Some((1, "c")));
+    }
+
+    #[test]
+    fn test_errors_hint() {
+        let source = vec![Ok(1), Err("a"), Ok(2), Err("b"), Ok(3)];
+
+        assert_eq!(
+            source.clone().into_iter().errors().size_hint(),
+            (0, Some(5))
+        );
+        assert_eq!(
+            source.into_iter().errors_with_index().size_hint(),
+            (0, Some(5))
+        );
+    }
 }
@@ -0,0 +1,167 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Conversions between the `Iterator<Item = Result<T, E>>` and the
+//! [`FallibleIterator<Item = T, Error = E>`](FallibleIterator).
+
+use fallible_iterator::{DoubleEndedFallibleIterator, FallibleIterator, IntoFallibleIterator};
+
+/// Extension trait for `Iterator<Item = Result<T, E>>` to turn it into a [`FallibleIterator`].
+pub trait IntoFallibleExt<T, E>: Sized {
+    /// Convert the iterator into a [`FallibleIterator`], yielding the `Ok` values
+    /// as its items and the `Err` values as its errors.
+    ///
+    /// Unlike most of the fallible iterators, the returned one could be advanced
+    /// after an error: the items following it are yielded the same way.
+    ///
+    /// ```
+    /// # extern crate fallible_iterator;
+    /// use fallible_iterator::FallibleIterator;
+    /// use resiter::fallible::IntoFallibleExt;
+    ///
+    /// let mut doubled = vec![Ok(1), Ok(2), Err("a"), Ok(3)]
+    ///     .into_iter()
+    ///     .into_fallible()
+    ///     .map(|i| Ok(i * 2));
+    ///
+    /// assert_eq!(doubled.next(), Ok(Some(2)));
+    /// assert_eq!(doubled.next(), Ok(Some(4)));
+    /// assert_eq!(doubled.next(), Err("a"));
+    /// ```
+    fn into_fallible(self) -> IntoFallible<Self>;
+}
+
+impl<I, T, E> IntoFallibleExt<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>> + Sized,
+{
+    #[inline]
+    fn into_fallible(self) -> IntoFallible<Self> {
+        IntoFallible(self)
+    }
+}
+
+/// [`FallibleIterator`] over the `Result`s of an `Iterator`.
+///
+/// Created with [`IntoFallibleExt::into_fallible`].
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct IntoFallible<I>(I);
+
+impl<I, T, E> FallibleIterator for IntoFallible<I>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+    type Error = E;
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<T>, E> {
+        self.0.next().transpose()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I, T, E> DoubleEndedFallibleIterator for IntoFallible<I>
+where
+    I: DoubleEndedIterator<Item = Result<T, E>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Result<Option<T>, E> {
+        self.0.next_back().transpose()
+    }
+}
+
+/// Convert a [`FallibleIterator`] into an `Iterator<Item = Result<T, E>>`,
+/// so it could be used with all the adapters of this crate.
+///
+/// Every error of the fallible iterator is yielded as an `Err`,
+/// and the iteration ends when the fallible iterator returns `Ok(None)`.
+///
+/// ```
+/// # extern crate fallible_iterator;
+/// use fallible_iterator::{convert, FallibleIterator};
+/// use resiter::fallible::from_fallible;
+/// use resiter::map::Map;
+///
+/// let source = convert(vec![Ok(1), Err("a"), Ok(3)].into_iter()).filter(|i| Ok(*i > 1));
+///
+/// let mapped: Vec<_> = from_fallible(source).map_ok(|i| i * 10).collect();
+/// assert_eq!(mapped, [Err("a"), Ok(30)]);
+/// ```
+pub fn from_fallible<I>(iter: I) -> FromFallible<I::IntoFallibleIter>
+where
+    I: IntoFallibleIterator,
+{
+    FromFallible(iter.into_fallible_iter())
+}
+
+/// `Iterator` over the items and the errors of a [`FallibleIterator`].
+///
+/// Created with [`from_fallible`].
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct FromFallible<I>(I);
+
+impl<I> Iterator for FromFallible<I>
+where
+    I: FallibleIterator,
+{
+    type Item = Result<I::Item, I::Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().transpose()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for FromFallible<I>
+where
+    I: DoubleEndedFallibleIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fallible_round_trip() {
+        let source = vec![Ok(1), Err("a"), Ok(2), Err("b"), Ok(3)];
+
+        let back: Vec<_> = from_fallible(source.clone().into_iter().into_fallible()).collect();
+        assert_eq!(back, source);
+
+        let reversed: Vec<_> = from_fallible(source.clone().into_iter().into_fallible())
+            .rev()
+            .collect();
+        assert_eq!(reversed, [Ok(3), Err("b"), Ok(2), Err("a"), Ok(1)]);
+    }
+
+    #[test]
+    fn test_fallible_combinators_in_between() {
+        let source = vec![Ok(1), Ok(2), Ok(3), Err("a"), Ok(4)].into_iter();
+
+        let mut fallible = source.into_fallible().filter(|i| Ok(i % 2 == 1));
+        assert_eq!(fallible.size_hint(), (0, Some(5)));
+
+        let items: Vec<_> = from_fallible(fallible.by_ref()).take(3).collect();
+        assert_eq!(items, [Ok(1), Ok(3), Err("a")]);
+    }
+}
@@ -66,6 +66,7 @@ where
     }
 }
 
+#[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct FilterOk<I, F> {
     iter: I,
@@ -101,6 +102,7 @@ where
     }
 }
 
+#[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct FilterErr<I, F> {
     iter: I,
@@ -6,9 +6,29 @@
 
 /// Extension trait for `Iterator<Item = Result<O, E>>` to selectively transform and map Oks and Errors.
 pub trait FilterMap<O, E>: Sized {
+    /// [filter_map](Iterator::filter_map) every `Ok` value while leaving `Err` as is
+    ///
+    /// The function either drops the value (returning `None`)
+    /// or maps it (returning `Some`), so there is no need to chain
+    /// [`map_ok`][crate::map::Map::map_ok] and [`filter_ok`][crate::filter::Filter::filter_ok].
+    ///
+    /// ```
+    /// use resiter::filter_map::FilterMap;
+    /// use std::str::FromStr;
+    ///
+    /// let evens: Vec<_> = ["1", "2", "a", "4", "5", "6"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|_| txt))
+    ///     .filter_map_ok(|i| if i % 2 == 0 { Some(i / 2) } else { None })
+    ///     .collect();
+    ///
+    /// assert_eq!(evens, [Ok(1), Err(&"a"), Ok(2), Ok(3)]);
+    /// ```
     fn filter_map_ok<F, O2>(self, _: F) -> FilterMapOk<Self, F>
     where
         F: FnMut(O) -> Option<O2>;
+
+    /// [filter_map](Iterator::filter_map) every `Err` value while leaving `Ok` as is
     fn filter_map_err<F, E2>(self, _: F) -> FilterMapErr<Self, F>
     where
         F: FnMut(E) -> Option<E2>;
@@ -84,6 +104,7 @@ where
     }
 }
 
+#[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct FilterMapOk<I, F> {
     iter: I,
@@ -117,6 +138,7 @@ where
     }
 }
 
+#[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct FilterMapErr<I, F> {
     iter: I,
@@ -0,0 +1,368 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<T, E>>` to search for the `Ok` or `Err` values.
+///
+/// All the methods stop as soon as the search is over, so the iteration can be resumed
+/// from the item right after the one found (or right before it, for the backward search).
+pub trait Find<T, E>: Iterator<Item = Result<T, E>> {
+    /// Search for the first `Ok` value that satisfies the predicate,
+    /// stopping on the first [`Err`] and returning it instead.
+    ///
+    /// The iterator is left right after the matched value or the [`Err`]
+    /// (or it gets exhausted if neither was found).
+    ///
+    /// ```
+    /// use resiter::find::Find;
+    ///
+    /// let mut iter = vec![Ok(1), Ok(2), Ok(3), Err("a"), Ok(4)].into_iter();
+    ///
+    /// assert_eq!(iter.find_ok(|&i| i > 1), Ok(Some(2)));
+    /// assert_eq!(iter.find_ok(|&i| i > 3), Err("a"));
+    /// assert_eq!(iter.find_ok(|&i| i > 4), Ok(None));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn find_ok<P>(&mut self, pred: P) -> Result<Option<T>, E>
+    where
+        P: FnMut(&T) -> bool;
+
+    /// Search for the first `Err` value that satisfies the predicate,
+    /// skipping all the `Ok` values.
+    ///
+    /// The iterator is left right after the matched error
+    /// (or it gets exhausted if none was found).
+    ///
+    /// ```
+    /// use resiter::find::Find;
+    ///
+    /// let mut iter = vec![Ok(1), Err("a"), Ok(3), Err("bb"), Ok(5)].into_iter();
+    ///
+    /// assert_eq!(iter.find_err(|e| e.len() > 1), Some("bb"));
+    /// assert_eq!(iter.next(), Some(Ok(5)));
+    /// ```
+    fn find_err<P>(&mut self, pred: P) -> Option<E>
+    where
+        P: FnMut(&E) -> bool;
+
+    /// Search for the first `Ok` value that satisfies the predicate returning its index,
+    /// stopping on the first [`Err`] and returning it instead.
+    ///
+    /// The index is counted over all the items, so it always matches the position
+    /// in the original iterator (any `Err` stops the search anyway).
+    /// The iterator is left right after the matched value or the [`Err`]
+    /// (or it gets exhausted if neither was found).
+    ///
+    /// ```
+    /// use resiter::find::Find;
+    ///
+    /// let mut iter = vec![Ok(1), Ok(2), Ok(3), Ok(4), Err("a"), Ok(6)].into_iter();
+    ///
+    /// assert_eq!(iter.position_ok(|i| i == 3), Ok(Some(2)));
+    /// assert_eq!(iter.next(), Some(Ok(4)));
+    /// assert_eq!(iter.position_ok(|i| i == 6), Err("a"));
+    /// assert_eq!(iter.position_ok(|i| i == 6), Ok(Some(0)));
+    /// ```
+    fn position_ok<P>(&mut self, pred: P) -> Result<Option<usize>, E>
+    where
+        P: FnMut(T) -> bool;
+
+    /// Return the `n`th `Ok` value (zero-based) like [`Iterator::nth`] does,
+    /// stopping on the first [`Err`] and returning it instead.
+    ///
+    /// Only the `Ok` values are counted towards `n`, so the `Err` could never be skipped.
+    /// If there are not enough `Ok` values, `Ok(None)` is returned.
+    ///
+    /// ```
+    /// use resiter::find::Find;
+    ///
+    /// let mut iter = vec![Ok(1), Ok(2), Ok(3), Ok(4), Err("a"), Ok(6)].into_iter();
+    ///
+    /// assert_eq!(iter.nth_ok(1), Ok(Some(2)));
+    /// assert_eq!(iter.nth_ok(1), Ok(Some(4)));
+    /// assert_eq!(iter.nth_ok(1), Err("a"));
+    /// assert_eq!(iter.nth_ok(1), Ok(None));
+    /// ```
+    fn nth_ok(&mut self, n: usize) -> Result<Option<T>, E>;
+
+    /// Apply the function to the `Ok` values, returning the first non-`None` result,
+    /// stopping on the first [`Err`] and returning it instead.
+    ///
+    /// The iterator is left right after the matched value or the [`Err`]
+    /// (or it gets exhausted if neither was found).
+    ///
+    /// ```
+    /// use resiter::find::Find;
+    ///
+    /// let mut iter = vec![Ok("a"), Ok("2"), Err("io"), Ok("4")].into_iter();
+    ///
+    /// assert_eq!(iter.find_map_ok(|s| s.parse::<u8>().ok()), Ok(Some(2)));
+    /// assert_eq!(iter.find_map_ok(|s| s.parse::<u8>().ok()), Err("io"));
+    /// assert_eq!(iter.find_map_ok(|s| s.parse::<u8>().ok()), Ok(Some(4)));
+    /// ```
+    fn find_map_ok<U, F>(&mut self, f: F) -> Result<Option<U>, E>
+    where
+        F: FnMut(T) -> Option<U>;
+
+    /// Search for the last `Ok` value that satisfies the predicate returning its index,
+    /// like [`Iterator::rposition`] does, stopping on the first [`Err`] from the back
+    /// and returning it instead.
+    ///
+    /// The index is counted from the front, same as for the
+    /// [`position_ok`](Find::position_ok).
+    /// The iterator is left right before the matched value or the [`Err`],
+    /// so the search could be continued with the next call.
+    ///
+    /// ```
+    /// use resiter::find::Find;
+    ///
+    /// let mut iter = vec![Ok(1), Err("a"), Ok(3), Ok(4), Ok(3)].into_iter();
+    ///
+    /// assert_eq!(iter.rposition_ok(|i| i == 3), Ok(Some(4)));
+    /// assert_eq!(iter.rposition_ok(|i| i == 1), Err("a"));
+    /// assert_eq!(iter.rposition_ok(|i| i == 1), Ok(Some(0)));
+    /// ```
+    fn rposition_ok<P>(&mut self, pred: P) -> Result<Option<usize>, E>
+    where
+        Self: DoubleEndedIterator + ExactSizeIterator,
+        P: FnMut(T) -> bool;
+
+    /// Test if every `Ok` value satisfies the predicate, like [`Iterator::all`] does,
+    /// stopping on the first [`Err`] and returning it instead.
+    ///
+    /// The iteration stops on whatever comes first: the `Ok` value failing the predicate
+    /// (returning `Ok(false)`) or the [`Err`], so the errors after a decisive value
+    /// are never seen.
+    ///
+    /// ```
+    /// use resiter::find::Find;
+    ///
+    /// let mut iter = vec![Ok(2), Ok(3), Err("a"), Ok(5)].into_iter();
+    ///
+    /// assert_eq!(iter.try_all(|i| i % 2 == 0), Ok(false));
+    /// assert_eq!(iter.try_all(|i| i % 2 == 0), Err("a"));
+    /// assert_eq!(iter.try_all(|i| i > 4), Ok(true));
+    /// ```
+    fn try_all<P>(&mut self, pred: P) -> Result<bool, E>
+    where
+        P: FnMut(T) -> bool;
+
+    /// Test if any `Ok` value satisfies the predicate, like [`Iterator::any`] does,
+    /// stopping on the first [`Err`] and returning it instead.
+    ///
+    /// The iteration stops on whatever comes first: the `Ok` value satisfying the predicate
+    /// (returning `Ok(true)`) or the [`Err`], so the errors after a decisive value
+    /// are never seen.
+    ///
+    /// ```
+    /// use resiter::find::Find;
+    ///
+    /// let mut iter = vec![Ok(1), Ok(2), Err("a"), Ok(5)].into_iter();
+    ///
+    /// assert_eq!(iter.try_any(|i| i % 2 == 0), Ok(true));
+    /// assert_eq!(iter.try_any(|i| i % 2 == 0), Err("a"));
+    /// assert_eq!(iter.try_any(|i| i % 2 == 0), Ok(false));
+    /// ```
+    fn try_any<P>(&mut self, pred: P) -> Result<bool, E>
+    where
+        P: FnMut(T) -> bool;
+}
+
+impl<I, T, E> Find<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    fn find_ok<P>(&mut self, mut pred: P) -> Result<Option<T>, E>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        for res in self {
+            let o = res?;
+            if pred(&o) {
+                return Ok(Some(o));
+            }
+        }
+        Ok(None)
+    }
+
+    fn find_err<P>(&mut self, mut pred: P) -> Option<E>
+    where
+        P: FnMut(&E) -> bool,
+    {
+        self.filter_map(Result::err).find(|e| pred(e))
+    }
+
+    fn position_ok<P>(&mut self, mut pred: P) -> Result<Option<usize>, E>
+    where
+        P: FnMut(T) -> bool,
+    {
+        for (i, res) in self.enumerate() {
+            if pred(res?) {
+                return Ok(Some(i));
+            }
+        }
+        Ok(None)
+    }
+
+    fn nth_ok(&mut self, mut n: usize) -> Result<Option<T>, E> {
+        for res in self {
+            let o = res?;
+            if n == 0 {
+                return Ok(Some(o));
+            }
+            n -= 1;
+        }
+        Ok(None)
+    }
+
+    fn find_map_ok<U, F>(&mut self, mut f: F) -> Result<Option<U>, E>
+    where
+        F: FnMut(T) -> Option<U>,
+    {
+        for res in self {
+            if let Some(u) = f(res?) {
+                return Ok(Some(u));
+            }
+        }
+        Ok(None)
+    }
+
+    fn rposition_ok<P>(&mut self, mut pred: P) -> Result<Option<usize>, E>
+    where
+        Self: DoubleEndedIterator + ExactSizeIterator,
+        P: FnMut(T) -> bool,
+    {
+        let mut i = self.len();
+        while let Some(res) = self.next_back() {
+            i -= 1;
+            if pred(res?) {
+                return Ok(Some(i));
+            }
+        }
+        Ok(None)
+    }
+
+    fn try_all<P>(&mut self, mut pred: P) -> Result<bool, E>
+    where
+        P: FnMut(T) -> bool,
+    {
+        for res in self {
+            if !pred(res?) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn try_any<P>(&mut self, mut pred: P) -> Result<bool, E>
+    where
+        P: FnMut(T) -> bool,
+    {
+        for res in self {
+            if pred(res?) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_ok_error_before_match() {
+        let mut iter = vec![Ok(1), Err("a"), Ok(3), Ok(4)].into_iter();
+
+        assert_eq!(iter.find_ok(|&i| i > 2), Err("a"));
+        assert_eq!(iter.next(), Some(Ok(3)));
+    }
+
+    #[test]
+    fn test_position_ok_error_before_match() {
+        let mut iter = vec![Ok(1), Err("a"), Ok(3), Ok(4)].into_iter();
+
+        assert_eq!(iter.position_ok(|i| i == 3), Err("a"));
+        assert_eq!(iter.next(), Some(Ok(3)));
+    }
+
+    #[test]
+    fn test_nth_ok_not_enough_oks() {
+        let mut iter = vec![Ok(1), Ok(2)].into_iter();
+
+        assert_eq!(iter.nth_ok(5), Ok::<_, &str>(None));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_nth_ok_error_before_target() {
+        let mut iter = vec![Ok(1), Err("a"), Ok(3), Ok(4)].into_iter();
+
+        assert_eq!(iter.nth_ok(2), Err("a"));
+        assert_eq!(iter.nth_ok(0), Ok(Some(3)));
+    }
+
+    #[test]
+    fn test_find_err_not_found() {
+        let mut iter = vec![Ok(1), Err("a"), Ok(3)].into_iter();
+
+        assert_eq!(iter.find_err(|e| e.is_empty()), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_find_map_ok_error_before_match() {
+        let mut iter = vec![Ok(1), Err("a"), Ok(3), Ok(4)].into_iter();
+
+        assert_eq!(
+            iter.find_map_ok(|i| if i > 2 { Some(i * 10) } else { None }),
+            Err("a")
+        );
+        assert_eq!(iter.next(), Some(Ok(3)));
+    }
+
+    #[test]
+    fn test_rposition_ok_error_from_the_back() {
+        let mut iter = vec![Ok(1), Err("a"), Ok(3), Err("b"), Ok(5)].into_iter();
+
+        assert_eq!(iter.rposition_ok(|i| i == 1), Err("b"));
+        assert_eq!(iter.next_back(), Some(Ok(3)));
+        assert_eq!(iter.rposition_ok(|i| i == 1), Err("a"));
+        assert_eq!(iter.rposition_ok(|i| i == 7), Ok(None));
+    }
+
+    #[test]
+    fn test_try_all_error_before_decision() {
+        let mut iter = vec![Ok(2), Err("a"), Ok(3)].into_iter();
+
+        assert_eq!(iter.try_all(|i| i % 2 == 0), Err("a"));
+        assert_eq!(iter.next(), Some(Ok(3)));
+    }
+
+    #[test]
+    fn test_try_all_error_after_decision() {
+        let mut iter = vec![Ok(2), Ok(3), Err("a")].into_iter();
+
+        assert_eq!(iter.try_all(|i| i % 2 == 0), Ok(false));
+        assert_eq!(iter.next(), Some(Err("a")));
+    }
+
+    #[test]
+    fn test_try_any_error_before_decision() {
+        let mut iter = vec![Ok(1), Err("a"), Ok(2)].into_iter();
+
+        assert_eq!(iter.try_any(|i| i % 2 == 0), Err("a"));
+        assert_eq!(iter.next(), Some(Ok(2)));
+    }
+
+    #[test]
+    fn test_try_any_error_after_decision() {
+        let mut iter = vec![Ok(1), Ok(2), Err("a")].into_iter();
+
+        assert_eq!(iter.try_any(|i| i % 2 == 0), Ok(true));
+        assert_eq!(iter.next(), Some(Err("a")));
+    }
+}
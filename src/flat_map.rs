@@ -17,6 +17,21 @@ pub trait FlatMap<O, E>: Sized {
     ///     .collect();
     /// assert_eq!(mapped, [Ok(0), Ok(0), Ok(1), Err(2), Err(0), Ok(0), Ok(1)]);
     /// ```
+    ///
+    /// Every `Ok` value expands into a run of `Ok` items,
+    /// while every `Err` passes through as a single item:
+    /// ```
+    /// use resiter::flat_map::FlatMap;
+    /// use std::str::FromStr;
+    ///
+    /// let expanded: Vec<_> = ["2", "x", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|_| txt))
+    ///     .flat_map_ok(|n| 1..=n)
+    ///     .collect();
+    ///
+    /// assert_eq!(expanded, [Ok(1), Ok(2), Err(&"x"), Ok(1), Ok(2), Ok(3)]);
+    /// ```
     fn flat_map_ok<U, F, O2>(self, _: F) -> FlatMapOk<Self, U, F>
     where
         F: FnMut(O) -> U,
@@ -84,6 +99,22 @@ where
     f: F,
 }
 
+impl<I, U, F> Clone for FlatMapOk<I, U, F>
+where
+    I: Clone,
+    U: IntoIterator,
+    U::IntoIter: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        FlatMapOk {
+            frontiter: self.frontiter.clone(),
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
 impl<I, O, E, F, O2, U> Iterator for FlatMapOk<I, U, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -110,10 +141,17 @@ where
     }
 
     #[inline]
-    // TODO: Oh dear, this hint could be much better
-    // https://doc.rust-lang.org/src/core/iter/mod.rs.html#2694
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        // every outer item could expand into any number of items,
+        // so only the in-progress inner iterator gives some certainty
+        let (lo, hi) = self
+            .frontiter
+            .as_ref()
+            .map_or((0, Some(0)), Iterator::size_hint);
+        match (self.iter.size_hint(), hi) {
+            ((_, Some(0)), Some(hi)) => (lo, Some(hi)),
+            _ => (lo, None),
+        }
     }
 }
 
@@ -124,6 +162,22 @@ pub struct FlatMapErr<I, U: IntoIterator, F> {
     f: F,
 }
 
+impl<I, U, F> Clone for FlatMapErr<I, U, F>
+where
+    I: Clone,
+    U: IntoIterator,
+    U::IntoIter: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        FlatMapErr {
+            frontiter: self.frontiter.clone(),
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
 impl<I, O, E, F, E2, U> Iterator for FlatMapErr<I, U, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -151,6 +205,44 @@ where
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        // every outer item could expand into any number of items,
+        // so only the in-progress inner iterator gives some certainty
+        let (lo, hi) = self
+            .frontiter
+            .as_ref()
+            .map_or((0, Some(0)), Iterator::size_hint);
+        match (self.iter.size_hint(), hi) {
+            ((_, Some(0)), Some(hi)) => (lo, Some(hi)),
+            _ => (lo, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flat_map_ok_hint() {
+        let mut iter = vec![Ok(0), Ok(2), Err("x")]
+            .into_iter()
+            .flat_map_ok(|n| 0..n);
+        assert_eq!(iter.size_hint(), (0, None));
+
+        assert_eq!(iter.next(), Some(Ok(0)));
+        assert_eq!(iter.size_hint(), (1, None));
+
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.next(), Some(Err("x")));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_flat_map_err_hint() {
+        let mut iter = vec![Err(2), Ok("a")].into_iter().flat_map_err(|n| 0..n);
+        assert_eq!(iter.size_hint(), (0, None));
+
+        assert_eq!(iter.next(), Some(Err(0)));
+        assert_eq!(iter.size_hint(), (1, None));
     }
 }
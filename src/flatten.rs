@@ -24,6 +24,19 @@ pub trait Flatten<O, E>: Sized {
     ///     [Ok(0), Ok(0), Ok(1), Err(0..4), Err(0..0), Ok(0), Ok(1)]
     /// );
     /// ```
+    ///
+    /// Every `Ok` collection expands into a run of `Ok` items,
+    /// while every `Err` passes through as a single item:
+    /// ```
+    /// use resiter::flatten::Flatten;
+    ///
+    /// let flattened: Vec<_> = vec![Ok(vec![1, 2]), Err("x"), Ok(vec![3])]
+    ///     .into_iter()
+    ///     .flatten_ok()
+    ///     .collect();
+    ///
+    /// assert_eq!(flattened, [Ok(1), Ok(2), Err("x"), Ok(3)]);
+    /// ```
     fn flatten_ok<U, O2>(self) -> FlattenOk<Self, U>
     where
         U: IntoIterator<Item = O2>;
@@ -94,6 +107,20 @@ where
     iter: I,
 }
 
+impl<I, U> Clone for FlattenOk<I, U>
+where
+    I: Clone,
+    U: IntoIterator,
+    U::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        FlattenOk {
+            frontiter: self.frontiter.clone(),
+            iter: self.iter.clone(),
+        }
+    }
+}
+
 impl<I, E, O2, U> Iterator for FlattenOk<I, U>
 where
     I: Iterator<Item = Result<U, E>>,
@@ -119,10 +146,17 @@ where
     }
 
     #[inline]
-    // TODO: Oh dear, this hint could be much better
-    // https://doc.rust-lang.org/src/core/iter/mod.rs.html#2694
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        // every outer item could expand into any number of items,
+        // so only the in-progress inner iterator gives some certainty
+        let (lo, hi) = self
+            .frontiter
+            .as_ref()
+            .map_or((0, Some(0)), Iterator::size_hint);
+        match (self.iter.size_hint(), hi) {
+            ((_, Some(0)), Some(hi)) => (lo, Some(hi)),
+            _ => (lo, None),
+        }
     }
 }
 
@@ -132,6 +166,20 @@ pub struct FlattenErr<I, U: IntoIterator> {
     iter: I,
 }
 
+impl<I, U> Clone for FlattenErr<I, U>
+where
+    I: Clone,
+    U: IntoIterator,
+    U::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        FlattenErr {
+            frontiter: self.frontiter.clone(),
+            iter: self.iter.clone(),
+        }
+    }
+}
+
 impl<I, O, E2, U> Iterator for FlattenErr<I, U>
 where
     I: Iterator<Item = Result<O, U>>,
@@ -158,6 +206,127 @@ where
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        // every outer item could expand into any number of items,
+        // so only the in-progress inner iterator gives some certainty
+        let (lo, hi) = self
+            .frontiter
+            .as_ref()
+            .map_or((0, Some(0)), Iterator::size_hint);
+        match (self.iter.size_hint(), hi) {
+            ((_, Some(0)), Some(hi)) => (lo, Some(hi)),
+            _ => (lo, None),
+        }
+    }
+}
+
+/// Extension trait for `Iterator<Item = Option<Result<O, E>>>` to skip the missing items.
+pub trait FlattenOptionsExt<O, E>: Sized {
+    /// Skip all the `None` items, yielding the wrapped `Result`s as is.
+    ///
+    /// This is a shortcut for the [`Iterator::flatten`], to get a plain
+    /// `Iterator<Item = Result<O, E>>` from a source wrapping the items in `Option`.
+    ///
+    /// ```
+    /// use resiter::flatten::FlattenOptionsExt;
+    ///
+    /// let flat: Vec<_> = vec![Some(Ok(1)), None, Some(Err("x")), None]
+    ///     .into_iter()
+    ///     .flatten_options()
+    ///     .collect();
+    ///
+    /// assert_eq!(flat, [Ok(1), Err("x")]);
+    /// ```
+    fn flatten_options(self) -> FlattenOptions<Self>;
+}
+
+impl<I, O, E> FlattenOptionsExt<O, E> for I
+where
+    I: Iterator<Item = Option<Result<O, E>>> + Sized,
+{
+    #[inline]
+    fn flatten_options(self) -> FlattenOptions<Self> {
+        FlattenOptions { iter: self }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct FlattenOptions<I> {
+    iter: I,
+}
+
+impl<I, O, E> Iterator for FlattenOptions<I>
+where
+    I: Iterator<Item = Option<Result<O, E>>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(res) = self.iter.next()? {
+                return Some(res);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+}
+
+impl<I, O, E> DoubleEndedIterator for FlattenOptions<I>
+where
+    I: DoubleEndedIterator<Item = Option<Result<O, E>>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(res) = self.iter.next_back()? {
+                return Some(res);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten_ok_hint() {
+        let mut iter = vec![Ok(vec![]), Ok(vec![1, 2]), Err("x")]
+            .into_iter()
+            .flatten_ok();
+        assert_eq!(iter.size_hint(), (0, None));
+
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.size_hint(), (1, None));
+
+        assert_eq!(iter.next(), Some(Ok(2)));
+        assert_eq!(iter.next(), Some(Err("x")));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_flatten_err_hint() {
+        let mut iter = vec![Err(vec!["a", "b"]), Ok(1)].into_iter().flatten_err();
+        assert_eq!(iter.size_hint(), (0, None));
+
+        assert_eq!(iter.next(), Some(Err("a")));
+        assert_eq!(iter.size_hint(), (1, None));
+    }
+
+    #[test]
+    fn test_flatten_options_both_ends() {
+        let mut flat = vec![None, Some(Ok(1)), None, Some(Err("a")), Some(Ok(3)), None]
+            .into_iter()
+            .flatten_options();
+
+        assert_eq!(flat.size_hint(), (0, Some(6)));
+        assert_eq!(flat.next_back(), Some(Ok(3)));
+        assert_eq!(flat.next(), Some(Ok(1)));
+        assert_eq!(flat.next(), Some(Err("a")));
+        assert_eq!(flat.next_back(), None);
     }
 }
@@ -0,0 +1,174 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<T, E>>` to fold all the `Ok` values
+/// into a single accumulated value.
+pub trait FoldOk<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Fold every `Ok` value into an accumulator, stopping on the first [`Err`].
+    ///
+    /// The items following the first [`Err`] are not consumed,
+    /// so the iteration can be resumed if the method was called on
+    /// [`by_ref`](Iterator::by_ref).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resiter::fold::FoldOk;
+    /// use std::str::FromStr;
+    ///
+    /// let sum = ["1", "2", "3", "4", "5"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .fold_ok(0, |acc, i| acc + i);
+    ///
+    /// assert_eq!(sum, Ok(15));
+    /// ```
+    ///
+    /// The first error is returned as is:
+    /// ```
+    /// use resiter::fold::FoldOk;
+    ///
+    /// let mut iter = vec![Ok(1), Ok(2), Err("error1"), Ok(3), Err("error2")].into_iter();
+    /// let sum = iter.by_ref().fold_ok(0, |acc, i| acc + i);
+    ///
+    /// assert_eq!(sum, Err("error1"));
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// ```
+    ///
+    /// See also for the unit-like results: [`FoldUnit::fail_fast`][crate::unit::FoldUnit::fail_fast].
+    fn fold_ok<A, F>(self, init: A, f: F) -> Result<A, E>
+    where
+        F: FnMut(A, T) -> A;
+
+    /// Fold every `Ok` value into an accumulator with a function that can fail,
+    /// stopping on the first [`Err`] either from the iterator or from the function.
+    ///
+    /// The items are processed one by one, so the first error wins:
+    /// the function is never called on an `Err` item (that error is returned right away),
+    /// and once the function fails no more items are pulled from the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resiter::fold::FoldOk;
+    ///
+    /// let checked_sum = |acc: u8, i: u8| acc.checked_add(i).ok_or("overflow");
+    ///
+    /// let sum = vec![Ok(100), Ok(100), Ok(50)].into_iter().try_fold_ok(0, checked_sum);
+    /// assert_eq!(sum, Ok(250));
+    ///
+    /// let mut iter = vec![Ok(100), Ok(100), Ok(100), Err("error")].into_iter();
+    /// let sum = iter.by_ref().try_fold_ok(0, checked_sum);
+    /// assert_eq!(sum, Err("overflow"));
+    /// assert_eq!(iter.next(), Some(Err("error")));
+    ///
+    /// let sum = vec![Ok(100), Err("error"), Ok(100), Ok(100)]
+    ///     .into_iter()
+    ///     .try_fold_ok(0, checked_sum);
+    /// assert_eq!(sum, Err("error"));
+    /// ```
+    fn try_fold_ok<A, F>(self, init: A, f: F) -> Result<A, E>
+    where
+        F: FnMut(A, T) -> Result<A, E>;
+
+    /// Call a function that can fail on every `Ok` value,
+    /// stopping on the first [`Err`] either from the iterator or from the function.
+    ///
+    /// This is the side-effecting counterpart of the [`try_fold_ok`](FoldOk::try_fold_ok)
+    /// with the same combined error channel: whichever error comes first wins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resiter::fold::FoldOk;
+    ///
+    /// let mut written = vec![];
+    /// let mut write = |i: i32| {
+    ///     if i < 0 {
+    ///         return Err("negative");
+    ///     }
+    ///     written.push(i);
+    ///     Ok(())
+    /// };
+    ///
+    /// let mut iter = vec![Ok(1), Ok(2), Ok(-3), Ok(4), Err("error")].into_iter();
+    /// assert_eq!(iter.try_for_each_ok(&mut write), Err("negative"));
+    /// assert_eq!(iter.try_for_each_ok(&mut write), Err("error"));
+    /// assert_eq!(iter.try_for_each_ok(&mut write), Ok(()));
+    ///
+    /// assert_eq!(written, [1, 2, 4]);
+    /// ```
+    fn try_for_each_ok<F>(&mut self, f: F) -> Result<(), E>
+    where
+        F: FnMut(T) -> Result<(), E>;
+
+    /// Call one of the functions on every item: `on_ok` for the `Ok` values
+    /// and `on_err` for the `Err` values.
+    ///
+    /// Unlike the other methods, this one never stops early:
+    /// the whole iterator is always consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resiter::fold::FoldOk;
+    ///
+    /// let mut inserted = vec![];
+    /// let mut logged = vec![];
+    ///
+    /// vec![Ok(1), Err("a"), Ok(2), Err("b")]
+    ///     .into_iter()
+    ///     .for_each_result(|i| inserted.push(i), |e| logged.push(e));
+    ///
+    /// assert_eq!(inserted, [1, 2]);
+    /// assert_eq!(logged, ["a", "b"]);
+    /// ```
+    fn for_each_result<FO, FE>(self, on_ok: FO, on_err: FE)
+    where
+        FO: FnMut(T),
+        FE: FnMut(E);
+}
+
+impl<I, T, E> FoldOk<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    #[inline]
+    fn fold_ok<A, F>(mut self, init: A, mut f: F) -> Result<A, E>
+    where
+        F: FnMut(A, T) -> A,
+    {
+        self.try_fold(init, |acc, res| res.map(|x| f(acc, x)))
+    }
+
+    #[inline]
+    fn try_fold_ok<A, F>(mut self, init: A, mut f: F) -> Result<A, E>
+    where
+        F: FnMut(A, T) -> Result<A, E>,
+    {
+        self.try_fold(init, |acc, res| res.and_then(|x| f(acc, x)))
+    }
+
+    #[inline]
+    fn try_for_each_ok<F>(&mut self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(T) -> Result<(), E>,
+    {
+        self.try_for_each(|res| res.and_then(&mut f))
+    }
+
+    #[inline]
+    fn for_each_result<FO, FE>(self, mut on_ok: FO, mut on_err: FE)
+    where
+        FO: FnMut(T),
+        FE: FnMut(E),
+    {
+        self.for_each(|res| match res {
+            Ok(t) => on_ok(t),
+            Err(e) => on_err(e),
+        })
+    }
+}
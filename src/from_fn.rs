@@ -0,0 +1,68 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Create an `Iterator<Item = Result<T, E>>` from a fallible closure,
+/// same as [`core::iter::from_fn`], but shaped for the resiter adapters.
+///
+/// Every call of `f` produces the next item: `Some(Ok(_))` or `Some(Err(_))`,
+/// while `None` ends the iteration.
+/// The iterator is not fused: `f` is called again if the iteration is resumed.
+///
+/// ```
+/// use resiter::from_fn::from_fallible_fn;
+/// use resiter::map::Map;
+///
+/// let mut records = vec!["3", "x", "1"];
+/// let parsed: Vec<_> = from_fallible_fn(|| records.pop().map(str::parse::<u8>))
+///     .map_err(|_| "not a number")
+///     .collect();
+///
+/// assert_eq!(parsed, [Ok(1), Err("not a number"), Ok(3)]);
+/// ```
+pub fn from_fallible_fn<T, E, F>(f: F) -> FromFallibleFn<F>
+where
+    F: FnMut() -> Option<Result<T, E>>,
+{
+    FromFallibleFn(f)
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct FromFallibleFn<F>(F);
+
+impl<T, E, F> Iterator for FromFallibleFn<F>
+where
+    F: FnMut() -> Option<Result<T, E>>,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.0)()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_fallible_fn_resumes() {
+        let mut calls = 0;
+        let mut iter = from_fallible_fn(|| {
+            calls += 1;
+            match calls {
+                1 => Some(Ok(calls)),
+                2 => None,
+                _ => Some(Err("resumed")),
+            }
+        });
+
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), Some(Err("resumed")));
+    }
+}
@@ -0,0 +1,190 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#[cfg(not(test))]
+use core::iter::FusedIterator;
+#[cfg(test)]
+use std::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to stop the iteration for good.
+pub trait Fuse<O, E>: Sized {
+    /// Yield all the `Ok` values and the first `Err`, then stop permanently.
+    ///
+    /// After the first `Err` (or the end of the underlying iterator),
+    /// [`next`](Iterator::next) always returns `None`,
+    /// regardless of what is left in the underlying iterator.
+    ///
+    /// Unlike the [`WhileOk`][crate::while_ok::WhileOk], the terminating error
+    /// is yielded to the caller as a regular item.
+    ///
+    /// ```
+    /// use resiter::fuse::Fuse;
+    ///
+    /// let fused: Vec<_> = vec![Ok(1), Ok(2), Err("a"), Ok(3), Err("b")]
+    ///     .into_iter()
+    ///     .fuse_err()
+    ///     .collect();
+    ///
+    /// assert_eq!(fused, [Ok(1), Ok(2), Err("a")]);
+    /// ```
+    fn fuse_err(self) -> FuseErr<Self>;
+
+    /// Yield all the items while tolerating no more than `limit` errors:
+    /// the next one after them (the `limit + 1`-th) is yielded as well,
+    /// then the iteration stops permanently.
+    ///
+    /// So the `limit` is the number of errors to pass through normally,
+    /// and [`fuse_err`](Fuse::fuse_err) is the same as the `limit` of 0.
+    /// After the last tolerated error (or the end of the underlying iterator),
+    /// [`next`](Iterator::next) always returns `None`.
+    ///
+    /// ```
+    /// use resiter::fuse::Fuse;
+    ///
+    /// let items: Vec<_> = vec![Ok(1), Err("a"), Ok(2), Err("b"), Ok(3), Err("c"), Ok(4)]
+    ///     .into_iter()
+    ///     .abort_after_errors(1)
+    ///     .collect();
+    ///
+    /// assert_eq!(items, [Ok(1), Err("a"), Ok(2), Err("b")]);
+    /// ```
+    fn abort_after_errors(self, limit: usize) -> AbortAfterErrors<Self>;
+}
+
+impl<I, O, E> Fuse<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn fuse_err(self) -> FuseErr<Self> {
+        FuseErr {
+            iter: self,
+            done: false,
+        }
+    }
+
+    #[inline]
+    fn abort_after_errors(self, limit: usize) -> AbortAfterErrors<Self> {
+        AbortAfterErrors {
+            iter: self,
+            limit,
+            errors: 0,
+            done: false,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct FuseErr<I> {
+    iter: I,
+    done: bool,
+}
+
+impl<I, O, E> Iterator for FuseErr<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let item = self.iter.next();
+        if !matches!(item, Some(Ok(_))) {
+            self.done = true;
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (lo, hi) = self.iter.size_hint();
+            (lo.min(1), hi)
+        }
+    }
+}
+
+impl<I, O, E> FusedIterator for FuseErr<I> where I: Iterator<Item = Result<O, E>> {}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct AbortAfterErrors<I> {
+    iter: I,
+    limit: usize,
+    errors: usize,
+    done: bool,
+}
+
+impl<I, O, E> Iterator for AbortAfterErrors<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let item = self.iter.next();
+        match item {
+            Some(Ok(_)) => {}
+            Some(Err(_)) if self.errors < self.limit => self.errors += 1,
+            _ => self.done = true,
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (lo, hi) = self.iter.size_hint();
+            let errors_left = self.limit - self.errors;
+            (lo.min(errors_left.saturating_add(1)), hi)
+        }
+    }
+}
+
+impl<I, O, E> FusedIterator for AbortAfterErrors<I> where I: Iterator<Item = Result<O, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuse_err_nothing_after_error() {
+        let mut fused = vec![Ok(1), Err("a"), Ok(2), Err("b")]
+            .into_iter()
+            .fuse_err();
+
+        assert_eq!(fused.next(), Some(Ok(1)));
+        assert_eq!(fused.next(), Some(Err("a")));
+        assert_eq!(fused.size_hint(), (0, Some(0)));
+        assert_eq!(fused.next(), None);
+        assert_eq!(fused.next(), None);
+    }
+
+    #[test]
+    fn test_abort_after_errors_three_errors() {
+        let mut iter = vec![Err("a"), Ok(1), Err("b"), Ok(2), Err("c"), Ok(3), Err("d")]
+            .into_iter()
+            .abort_after_errors(2);
+
+        assert_eq!(iter.by_ref().take(4).count(), 4);
+        assert_eq!(iter.next(), Some(Err("c")));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,402 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to do something with the items
+/// without changing them.
+///
+/// The adapters are the same as [`OnOkDo`][crate::onok::OnOkDo] and
+/// [`OnErrDo`][crate::onerr::OnErrDo], but they follow the naming of [`Iterator::inspect`]
+/// and keep the [`size_hint`](Iterator::size_hint) of the underlying iterator.
+pub trait Inspect<O, E>: Sized {
+    /// [inspect](Iterator::inspect) every `Ok` value and leave all `Err` as is
+    ///
+    /// ```
+    /// use resiter::inspect::Inspect;
+    /// use std::str::FromStr;
+    ///
+    /// let mut oks = Vec::new();
+    /// let parsed: Vec<_> = ["1", "2", "a", "b", "5"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .inspect_ok(|i| {
+    ///         println!("Parsed: {}", i);
+    ///         oks.push(*i);
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(oks, vec![1, 2, 5]);
+    /// assert_eq!(parsed.len(), 5);
+    /// ```
+    fn inspect_ok<F>(self, _: F) -> InspectOk<Self, F>
+    where
+        F: FnMut(&O);
+
+    /// [inspect](Iterator::inspect) every `Err` value and leave all `Ok` as is
+    ///
+    /// ```
+    /// use resiter::inspect::Inspect;
+    /// use std::str::FromStr;
+    ///
+    /// let mut errors = 0;
+    /// let parsed: Vec<_> = ["1", "2", "a", "b", "5"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .inspect_err(|e| {
+    ///         println!("Error happened: {:?}", e);
+    ///         errors += 1;
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(errors, 2);
+    /// assert_eq!(parsed.len(), 5);
+    /// ```
+    fn inspect_err<F>(self, _: F) -> InspectErr<Self, F>
+    where
+        F: FnMut(&E);
+
+    /// [inspect](Iterator::inspect) every item, calling `on_ok` for the `Ok` values
+    /// and `on_err` for the `Err` ones, and leave all items as is
+    ///
+    /// It is the same as [`inspect_ok`](Inspect::inspect_ok) followed by the
+    /// [`inspect_err`](Inspect::inspect_err), but in a single adapter.
+    ///
+    /// ```
+    /// use resiter::inspect::Inspect;
+    /// use std::str::FromStr;
+    ///
+    /// let (mut oks, mut errors) = (0, 0);
+    /// let parsed: Vec<_> = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|_| txt))
+    ///     .tap(
+    ///         |i| {
+    ///             println!("Parsed: {}", i);
+    ///             oks += 1;
+    ///         },
+    ///         |e| {
+    ///             println!("Failed to parse: {}", e);
+    ///             errors += 1;
+    ///         },
+    ///     )
+    ///     .collect();
+    ///
+    /// assert_eq!(parsed, [Ok(1), Err(&"a"), Ok(3)]);
+    /// assert_eq!((oks, errors), (2, 1));
+    /// ```
+    fn tap<FO, FE>(self, on_ok: FO, on_err: FE) -> Tap<Self, FO, FE>
+    where
+        FO: FnMut(&O),
+        FE: FnMut(&E);
+
+    /// Report the number of `Ok` and `Err` values processed so far
+    /// as `f(ok_count, err_count)` after every `every` items, leaving all the items as is.
+    ///
+    /// When the underlying iterator is exhausted, one more final report is made,
+    /// unless the counts have not changed since the last one.
+    /// With the `every` of 0 only this final report is made.
+    ///
+    /// ```
+    /// use resiter::inspect::Inspect;
+    ///
+    /// let mut reports = Vec::new();
+    /// let items: Vec<_> = vec![Ok(1), Err("a"), Ok(3), Ok(4), Err("b")]
+    ///     .into_iter()
+    ///     .report_progress(2, |oks, errors| reports.push((oks, errors)))
+    ///     .collect();
+    ///
+    /// assert_eq!(items.len(), 5);
+    /// assert_eq!(reports, [(1, 1), (3, 1), (3, 2)]);
+    /// ```
+    fn report_progress<F>(self, every: usize, _: F) -> ReportProgress<Self, F>
+    where
+        F: FnMut(usize, usize);
+}
+
+impl<I, O, E> Inspect<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn inspect_ok<F>(self, f: F) -> InspectOk<Self, F>
+    where
+        F: FnMut(&O),
+    {
+        InspectOk { iter: self, f }
+    }
+
+    #[inline]
+    fn inspect_err<F>(self, f: F) -> InspectErr<Self, F>
+    where
+        F: FnMut(&E),
+    {
+        InspectErr { iter: self, f }
+    }
+
+    #[inline]
+    fn tap<FO, FE>(self, on_ok: FO, on_err: FE) -> Tap<Self, FO, FE>
+    where
+        FO: FnMut(&O),
+        FE: FnMut(&E),
+    {
+        Tap {
+            iter: self,
+            on_ok,
+            on_err,
+        }
+    }
+
+    #[inline]
+    fn report_progress<F>(self, every: usize, f: F) -> ReportProgress<Self, F>
+    where
+        F: FnMut(usize, usize),
+    {
+        ReportProgress {
+            iter: self,
+            every,
+            f,
+            oks: 0,
+            errors: 0,
+            reported: 0,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct InspectOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, O, E, F> Iterator for InspectOk<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&O),
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|r| {
+            if let Ok(ref o) = r {
+                (self.f)(o);
+            }
+            r
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, O, E, F> DoubleEndedIterator for InspectOk<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(&O),
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|r| {
+            if let Ok(ref o) = r {
+                (self.f)(o);
+            }
+            r
+        })
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct InspectErr<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, O, E, F> Iterator for InspectErr<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&E),
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|r| {
+            if let Err(ref e) = r {
+                (self.f)(e);
+            }
+            r
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct Tap<I, FO, FE> {
+    iter: I,
+    on_ok: FO,
+    on_err: FE,
+}
+
+impl<I, FO, FE> Tap<I, FO, FE> {
+    fn call<O, E>(&mut self, item: &Result<O, E>)
+    where
+        FO: FnMut(&O),
+        FE: FnMut(&E),
+    {
+        match item {
+            Ok(o) => (self.on_ok)(o),
+            Err(e) => (self.on_err)(e),
+        }
+    }
+}
+
+impl<I, O, E, FO, FE> Iterator for Tap<I, FO, FE>
+where
+    I: Iterator<Item = Result<O, E>>,
+    FO: FnMut(&O),
+    FE: FnMut(&E),
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.call(&item);
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, O, E, FO, FE> DoubleEndedIterator for Tap<I, FO, FE>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    FO: FnMut(&O),
+    FE: FnMut(&E),
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        self.call(&item);
+        Some(item)
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ReportProgress<I, F> {
+    iter: I,
+    every: usize,
+    f: F,
+    oks: usize,
+    errors: usize,
+    reported: usize,
+}
+
+impl<I, F> ReportProgress<I, F>
+where
+    F: FnMut(usize, usize),
+{
+    fn report(&mut self) {
+        self.reported = self.oks + self.errors;
+        (self.f)(self.oks, self.errors);
+    }
+}
+
+impl<I, O, E, F> Iterator for ReportProgress<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(usize, usize),
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+        match item {
+            Some(Ok(_)) => self.oks += 1,
+            Some(Err(_)) => self.errors += 1,
+            None => {
+                if self.reported != self.oks + self.errors {
+                    self.report();
+                }
+                return None;
+            }
+        }
+
+        if self.oks + self.errors - self.reported == self.every {
+            self.report();
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inspect_ok_hint() {
+        let hint = vec![Ok(1), Err("a"), Ok(3)]
+            .into_iter()
+            .inspect_ok(|_| {})
+            .size_hint();
+
+        assert_eq!(hint, (3, Some(3)));
+    }
+
+    #[test]
+    fn test_inspect_err_hint() {
+        let hint = vec![Ok(1), Err("a"), Ok(3)]
+            .into_iter()
+            .inspect_err(|_| {})
+            .size_hint();
+
+        assert_eq!(hint, (3, Some(3)));
+    }
+
+    #[test]
+    fn test_tap_hint() {
+        let hint = vec![Ok(1), Err("a"), Ok(3)]
+            .into_iter()
+            .tap(|_| {}, |_| {})
+            .size_hint();
+
+        assert_eq!(hint, (3, Some(3)));
+    }
+
+    #[test]
+    fn test_report_progress_final_report() {
+        let mut reports = Vec::new();
+        let mut iter = vec![Ok(1), Err("a"), Ok(3), Ok(4)]
+            .into_iter()
+            .report_progress(2, |oks, errors| reports.push((oks, errors)));
+
+        while iter.next().is_some() {}
+        // no new items since the last report
+        assert_eq!(iter.next(), None);
+        drop(iter);
+        assert_eq!(reports, [(1, 1), (3, 1)]);
+
+        reports.clear();
+        let count = vec![Ok(1), Err("a"), Ok(3)]
+            .into_iter()
+            .report_progress(0, |oks, errors| reports.push((oks, errors)))
+            .count();
+        assert_eq!(count, 3);
+        assert_eq!(reports, [(2, 1)]);
+    }
+}
@@ -0,0 +1,114 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#[cfg(not(test))]
+use core::iter::Fuse;
+#[cfg(test)]
+use std::iter::Fuse;
+
+/// Extension trait for `Iterator<Item = Result<T, E>>` to alternate the items
+/// with the ones from another `Iterator<Item = Result<T, E>>`.
+pub trait Interleave<T, E>: Sized {
+    /// Alternate the items of both iterators, starting with `self`.
+    ///
+    /// The `Err` values are not treated specially: every error takes its turn
+    /// and is yielded at the place it occurs in its own side.
+    ///
+    /// When one of the sides is exhausted, the rest of the other side
+    /// is yielded as is.
+    ///
+    /// ```
+    /// use resiter::interleave::Interleave;
+    ///
+    /// let odd = vec![Ok(1), Err("a"), Ok(5), Ok(7)];
+    /// let even = vec![Ok(2), Ok(4)];
+    ///
+    /// let all: Vec<_> = odd.into_iter().interleave_ok(even).collect();
+    /// assert_eq!(all, [Ok(1), Ok(2), Err("a"), Ok(4), Ok(5), Ok(7)]);
+    /// ```
+    fn interleave_ok<J>(self, other: J) -> InterleaveOk<Self, J::IntoIter>
+    where
+        J: IntoIterator<Item = Result<T, E>>;
+}
+
+impl<I, T, E> Interleave<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>> + Sized,
+{
+    #[inline]
+    fn interleave_ok<J>(self, other: J) -> InterleaveOk<Self, J::IntoIter>
+    where
+        J: IntoIterator<Item = Result<T, E>>,
+    {
+        InterleaveOk {
+            a: self.fuse(),
+            b: other.into_iter().fuse(),
+            b_turn: false,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct InterleaveOk<I, J> {
+    a: Fuse<I>,
+    b: Fuse<J>,
+    b_turn: bool,
+}
+
+impl<I, J, T, E> Iterator for InterleaveOk<I, J>
+where
+    I: Iterator<Item = Result<T, E>>,
+    J: Iterator<Item = Result<T, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.b_turn = !self.b_turn;
+        if self.b_turn {
+            self.a.next().or_else(|| self.b.next())
+        } else {
+            self.b.next().or_else(|| self.a.next())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lo, a_hi) = self.a.size_hint();
+        let (b_lo, b_hi) = self.b.size_hint();
+        let hi = match (a_hi, b_hi) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (a_lo.saturating_add(b_lo), hi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interleave_ok_error_on_the_right() {
+        let all: Vec<_> = vec![Ok(1), Ok(3), Ok(5)]
+            .into_iter()
+            .interleave_ok(vec![Ok(2), Err("b"), Ok(6), Ok(8), Err("c")])
+            .collect();
+
+        assert_eq!(
+            all,
+            [Ok(1), Ok(2), Ok(3), Err("b"), Ok(5), Ok(6), Ok(8), Err("c")]
+        );
+    }
+
+    #[test]
+    fn test_interleave_ok_hint() {
+        let iter = vec![Ok(1), Err("a")]
+            .into_iter()
+            .interleave_ok(vec![Ok(2), Ok(3), Ok(4)]);
+
+        assert_eq!(iter.size_hint(), (5, Some(5)));
+    }
+}
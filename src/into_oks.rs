@@ -0,0 +1,114 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#[cfg(not(test))]
+use core::marker::PhantomData;
+#[cfg(test)]
+use std::marker::PhantomData;
+
+/// Extension trait for any `Iterator<Item = T>` to turn it into `Iterator<Item = Result<T, E>>`.
+pub trait IntoOksExt<T>: Sized {
+    /// Wrap every item into `Ok`, so the iterator could be chained
+    /// with the fallible adapters.
+    ///
+    /// This is the same as `.map(Ok)`, but the error type could be specified
+    /// right away, with the turbofish.
+    ///
+    /// ```
+    /// use resiter::and_then::AndThen;
+    /// use resiter::into_oks::IntoOksExt;
+    ///
+    /// let checked: Vec<_> = (0..3)
+    ///     .into_oks::<&str>()
+    ///     .and_then_ok(|i| if i != 1 { Ok(i * 10) } else { Err("one") })
+    ///     .collect();
+    ///
+    /// assert_eq!(checked, [Ok(0), Err("one"), Ok(20)]);
+    /// ```
+    fn into_oks<E>(self) -> IntoOks<Self, E>;
+}
+
+impl<I, T> IntoOksExt<T> for I
+where
+    I: Iterator<Item = T> + Sized,
+{
+    #[inline]
+    fn into_oks<E>(self) -> IntoOks<Self, E> {
+        IntoOks {
+            iter: self,
+            _error: PhantomData,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct IntoOks<I, E> {
+    iter: I,
+    _error: PhantomData<fn() -> E>,
+}
+
+impl<I, E> Clone for IntoOks<I, E>
+where
+    I: Clone,
+{
+    fn clone(&self) -> Self {
+        IntoOks {
+            iter: self.iter.clone(),
+            _error: PhantomData,
+        }
+    }
+}
+
+impl<I, E> Iterator for IntoOks<I, E>
+where
+    I: Iterator,
+{
+    type Item = Result<I::Item, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Ok)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, E> DoubleEndedIterator for IntoOks<I, E>
+where
+    I: DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Ok)
+    }
+}
+
+impl<I, E> ExactSizeIterator for IntoOks<I, E>
+where
+    I: ExactSizeIterator,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_oks_exact_size() {
+        let mut oks = vec!['a', 'b', 'c'].into_iter().into_oks::<()>();
+
+        assert_eq!(oks.len(), 3);
+        assert_eq!(oks.next_back(), Some(Ok('c')));
+        assert_eq!(oks.clone().collect::<Vec<_>>(), [Ok('a'), Ok('b')]);
+    }
+}
@@ -21,6 +21,19 @@
 //! If a feature of this crate uses external dependencies, it should be hidden behind a feature
 //! gate. The crate itself should be usable without any dependencies besides `std`!
 //!
+//! The crate is `no_std`: all the lazy adapters only require `core`.
+//! The feature-gates are:
+//!
+//! * `std` (enabled by default): the helpers requiring the standard library, implies `alloc`
+//! * `alloc`: the helpers collecting the items into a `Vec` or other allocated types
+//! * `fallible-iterator`: the conversions to and from the
+//!   [`FallibleIterator`](https://docs.rs/fallible-iterator)
+//! * `log`: log the errors with the [`log`](https://docs.rs/log) crate
+//! * `rand`: the random sampling with the [`rand`](https://docs.rs/rand) crate, implies `alloc`
+//! * `stream`: adapters for the [`Stream`](https://docs.rs/futures-core)s of `Result`s
+//! * `futures-timer`: the [`Stream`](https://docs.rs/futures-core) timeouts with the
+//!   [`futures-timer`](https://docs.rs/futures-timer) crate, implies `stream` and `std`
+//!
 //! # Features
 //!
 //! Features included in this crate:
@@ -201,41 +214,150 @@
 
 #![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "fallible-iterator")]
+extern crate fallible_iterator;
+#[cfg(test)]
+extern crate futures;
+#[cfg(feature = "stream")]
+extern crate futures_core;
+#[cfg(feature = "futures-timer")]
+extern crate futures_timer;
+#[cfg(feature = "log")]
+extern crate log;
+#[cfg(feature = "stream")]
+extern crate pin_project_lite;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(all(test, feature = "rand"))]
+extern crate rand_chacha;
+#[cfg(all(feature = "std", not(test)))]
+extern crate std;
+
 pub mod and_then;
+pub mod assert;
+#[cfg(feature = "alloc")]
+pub mod boxed;
+#[cfg(feature = "alloc")]
+pub mod chunks;
+pub mod coalesce;
+pub mod collect;
+pub mod count;
+#[cfg(feature = "std")]
+pub mod deadline;
+pub mod dedup;
+#[cfg(feature = "alloc")]
+pub mod defer;
+pub mod enumerate;
 pub mod errors;
+#[cfg(feature = "fallible-iterator")]
+pub mod fallible;
 pub mod filter;
 pub mod filter_map;
+pub mod find;
 pub mod flat_map;
 pub mod flatten;
+pub mod fold;
+pub mod from_fn;
+pub mod fuse;
+pub mod inspect;
+pub mod interleave;
+pub mod into_oks;
 pub mod map;
+pub mod map_or;
+pub mod merge;
 pub mod ok_or_else;
+#[cfg(feature = "log")]
+pub mod ok_or_log;
 pub mod oks;
 pub mod onerr;
 pub mod onok;
+pub mod peekable;
 pub mod prelude;
+pub mod reduce;
+pub mod retry;
+pub mod scan;
+pub mod skip;
+pub mod skip_while;
+#[cfg(feature = "alloc")]
+pub mod split;
+#[cfg(feature = "stream")]
+pub mod stream;
+pub mod take;
+pub mod take_while;
+pub mod throttle;
 pub mod try_filter;
 pub mod try_filter_map;
 pub mod try_map;
 pub mod unit;
+pub mod until_ok;
 pub mod unwrap;
 mod util;
 pub mod while_ok;
+pub mod zip;
 
 pub use and_then::AndThen;
+pub use assert::Assert;
+#[cfg(feature = "alloc")]
+pub use boxed::Boxed;
+#[cfg(feature = "alloc")]
+pub use chunks::Chunks;
+pub use coalesce::Coalesce;
+#[cfg(feature = "alloc")]
+pub use collect::CollectResult;
+pub use collect::TryCollect;
+#[cfg(feature = "alloc")]
+pub use collect::UnzipResults;
+pub use count::Count;
+#[cfg(feature = "std")]
+pub use deadline::Deadline;
+pub use dedup::Dedup;
+#[cfg(feature = "alloc")]
+pub use defer::Defer;
+pub use enumerate::Enumerate;
 pub use errors::GetErrors;
+#[cfg(feature = "fallible-iterator")]
+pub use fallible::{from_fallible, IntoFallibleExt};
 pub use filter::Filter;
 pub use filter_map::FilterMap;
+pub use find::Find;
 pub use flat_map::FlatMap;
-pub use flatten::Flatten;
+pub use flatten::{Flatten, FlattenOptionsExt};
+pub use fold::FoldOk;
+pub use from_fn::from_fallible_fn;
+pub use fuse::Fuse;
+pub use inspect::Inspect;
+pub use interleave::Interleave;
+pub use into_oks::IntoOksExt;
 pub use map::Map;
+pub use map_or::MapOr;
+pub use merge::merge_ok_sorted;
 pub use ok_or_else::{IterInnerOkOrElse, ResultOptionExt};
+#[cfg(feature = "log")]
+pub use ok_or_log::OkOrLogExt;
 pub use oks::GetOks;
 pub use onerr::OnErrDo;
-pub use onok::OnOkDo;
+pub use onok::{OnOkDo, OnOkMutDo};
+pub use peekable::Peekable;
+pub use reduce::Reduce;
+pub use retry::Retry;
+pub use scan::Scan;
+pub use skip::Skip;
+pub use skip_while::SkipWhile;
+#[cfg(feature = "alloc")]
+pub use split::Split;
+#[cfg(feature = "stream")]
+pub use stream::TryStreamResiterExt;
+pub use take::Take;
+pub use take_while::TakeWhile;
+pub use throttle::Throttle;
 pub use try_filter::TryFilter;
 pub use try_filter_map::TryFilterMap;
 pub use try_map::TryMap;
 pub use unit::FoldUnit;
-pub use unwrap::UnwrapWithExt;
+pub use until_ok::UntilOk;
+pub use unwrap::{UnwrapOkOrDefaultExt, UnwrapWithExt};
 pub use util::{GetErr, GetOk, Process};
 pub use while_ok::WhileOk;
+pub use zip::Zip;
@@ -4,6 +4,13 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(all(feature = "alloc", not(test)))]
+use core::fmt::Display;
+#[cfg(all(feature = "alloc", test))]
+use std::fmt::Display;
+
 /// Extension trait for `Iterator<Item = Result<O, E>>` to selectively transform Oks and Errors.
 pub trait Map<O, E>: Sized {
     /// Map all `Ok` items while leaving `Err` as is
@@ -24,10 +31,56 @@ pub trait Map<O, E>: Sized {
     /// assert_eq!(mapped[3], Ok(8));
     /// assert_eq!(mapped[4], Ok(10));
     /// ```
+    ///
+    /// The items could also be mapped from the back:
+    /// ```
+    /// use resiter::map::Map;
+    ///
+    /// let mut order = vec![];
+    /// let mapped: Vec<_> = vec![Ok(1), Err("a"), Ok(3)]
+    ///     .into_iter()
+    ///     .map_ok(|i| {
+    ///         order.push(i);
+    ///         2 * i
+    ///     })
+    ///     .rev()
+    ///     .collect();
+    ///
+    /// assert_eq!(mapped, [Ok(6), Err("a"), Ok(2)]);
+    /// assert_eq!(order, [3, 1]);
+    /// ```
     fn map_ok<F, O2>(self, _: F) -> MapOk<Self, F>
     where
         F: FnMut(O) -> O2;
 
+    /// Map all `Ok` items along with their index, while leaving `Err` as is
+    ///
+    /// The index only counts the `Ok` values, same as in the
+    /// [`enumerate_ok`](crate::enumerate::Enumerate::enumerate_ok):
+    /// the errors do not advance it.
+    ///
+    /// ```
+    /// use resiter::map::Map;
+    ///
+    /// let mapped: Vec<_> = vec![Ok("a"), Err(1), Ok("b"), Ok("c")]
+    ///     .into_iter()
+    ///     .map_ok_indexed(|i, s| format!("{}. {}", i + 1, s))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     mapped,
+    ///     [
+    ///         Ok("1. a".to_owned()),
+    ///         Err(1),
+    ///         Ok("2. b".to_owned()),
+    ///         Ok("3. c".to_owned())
+    ///     ]
+    /// );
+    /// ```
+    fn map_ok_indexed<F, O2>(self, _: F) -> MapOkIndexed<Self, F>
+    where
+        F: FnMut(usize, O) -> O2;
+
     /// Map all `Err` items while leaving `Ok` as is
     ///
     /// ```
@@ -53,6 +106,86 @@ pub trait Map<O, E>: Sized {
     fn map_err<F, E2>(self, _: F) -> MapErr<Self, F>
     where
         F: FnMut(E) -> E2;
+
+    /// Map all `Err` items with the access to the mutable state, while leaving `Ok` as is
+    ///
+    /// The state is owned by the adapter and could be retrieved
+    /// with the [`into_state`](MapErrWith::into_state) after the iteration.
+    ///
+    /// ```
+    /// use resiter::map::Map;
+    /// use std::str::FromStr;
+    ///
+    /// let mut iter = ["1", "2", "a", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|_| txt))
+    ///     .map_err_with(0, |count, txt| {
+    ///         *count += 1;
+    ///         format!("error #{}: {}", count, txt)
+    ///     });
+    ///
+    /// let mapped: Vec<_> = iter.by_ref().collect();
+    /// assert_eq!(
+    ///     mapped,
+    ///     [
+    ///         Ok(1),
+    ///         Ok(2),
+    ///         Err("error #1: a".to_owned()),
+    ///         Ok(4),
+    ///         Err("error #2: b".to_owned())
+    ///     ]
+    /// );
+    /// assert_eq!(iter.into_state(), 2);
+    /// ```
+    fn map_err_with<S, F, E2>(self, state: S, _: F) -> MapErrWith<Self, S, F>
+    where
+        F: FnMut(&mut S, E) -> E2;
+
+    /// Map all `Err` items along with their position, while leaving `Ok` as is
+    ///
+    /// The index counts every item (both `Ok` and `Err`), so it matches the position
+    /// of the error in the original iterator, same as in the
+    /// [`errors_with_index`](crate::errors::GetErrors::errors_with_index).
+    ///
+    /// ```
+    /// use resiter::map::Map;
+    ///
+    /// let parsed: Vec<_> = ["1", "2", "bad", "4"]
+    ///     .iter()
+    ///     .map(|line| line.parse::<u8>().map_err(|_| line))
+    ///     .with_error_context(|i, e| format!("line {}: {}", i, e))
+    ///     .collect();
+    ///
+    /// assert_eq!(parsed, [Ok(1), Ok(2), Err("line 2: bad".to_owned()), Ok(4)]);
+    /// ```
+    fn with_error_context<F, E2>(self, _: F) -> WithErrorContext<Self, F>
+    where
+        F: FnMut(usize, E) -> E2;
+
+    /// Convert every `Err` value into a `String` with its [`Display`] implementation.
+    ///
+    /// This is a shortcut for `.map_err(|e| e.to_string())`,
+    /// useful to unify the different error types for reporting.
+    ///
+    /// ```
+    /// use resiter::map::Map;
+    /// use std::io;
+    ///
+    /// let messages: Vec<Result<u8, String>> = vec![
+    ///     Ok(1),
+    ///     Err(io::Error::new(io::ErrorKind::NotFound, "no such file")),
+    ///     Ok(3),
+    /// ]
+    /// .into_iter()
+    /// .map_err_to_string()
+    /// .collect();
+    ///
+    /// assert_eq!(messages, [Ok(1), Err("no such file".to_string()), Ok(3)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn map_err_to_string(self) -> MapErrToString<Self>
+    where
+        E: Display;
 }
 
 impl<I, O, E> Map<O, E> for I
@@ -67,6 +200,18 @@ where
         MapOk { iter: self, f }
     }
 
+    #[inline]
+    fn map_ok_indexed<F, O2>(self, f: F) -> MapOkIndexed<Self, F>
+    where
+        F: FnMut(usize, O) -> O2,
+    {
+        MapOkIndexed {
+            iter: self,
+            f,
+            count: 0,
+        }
+    }
+
     #[inline]
     fn map_err<F, E2>(self, f: F) -> MapErr<Self, F>
     where
@@ -74,8 +219,42 @@ where
     {
         MapErr { iter: self, f }
     }
+
+    #[inline]
+    fn map_err_with<S, F, E2>(self, state: S, f: F) -> MapErrWith<Self, S, F>
+    where
+        F: FnMut(&mut S, E) -> E2,
+    {
+        MapErrWith {
+            iter: self,
+            state,
+            f,
+        }
+    }
+
+    #[inline]
+    fn with_error_context<F, E2>(self, f: F) -> WithErrorContext<Self, F>
+    where
+        F: FnMut(usize, E) -> E2,
+    {
+        WithErrorContext {
+            iter: self,
+            f,
+            index: 0,
+        }
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn map_err_to_string(self) -> MapErrToString<Self>
+    where
+        E: Display,
+    {
+        MapErrToString { iter: self }
+    }
 }
 
+#[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct MapOk<I, F> {
     iter: I,
@@ -99,6 +278,70 @@ where
     }
 }
 
+impl<I, O, E, F, O2> DoubleEndedIterator for MapOk<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> O2,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|r| r.map(&mut self.f))
+    }
+}
+
+impl<I, O, E, F, O2> ExactSizeIterator for MapOk<I, F>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> O2,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MapOkIndexed<I, F> {
+    iter: I,
+    f: F,
+    count: usize,
+}
+
+impl<I, O, E, F, O2> Iterator for MapOkIndexed<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(usize, O) -> O2,
+{
+    type Item = Result<O2, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|r| {
+            r.map(|o| {
+                let i = self.count;
+                self.count += 1;
+                (self.f)(i, o)
+            })
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, O, E, F, O2> ExactSizeIterator for MapOkIndexed<I, F>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(usize, O) -> O2,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct MapErr<I, F> {
     iter: I,
@@ -122,6 +365,148 @@ where
     }
 }
 
+impl<I, O, E, F, E2> DoubleEndedIterator for MapErr<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> E2,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|r| r.map_err(&mut self.f))
+    }
+}
+
+impl<I, O, E, F, E2> ExactSizeIterator for MapErr<I, F>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> E2,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MapErrWith<I, S, F> {
+    iter: I,
+    state: S,
+    f: F,
+}
+
+impl<I, S, F> MapErrWith<I, S, F> {
+    /// Get the state back, dropping the rest of the iterator
+    pub fn into_state(self) -> S {
+        self.state
+    }
+}
+
+impl<I, O, E, S, F, E2> Iterator for MapErrWith<I, S, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&mut S, E) -> E2,
+{
+    type Item = Result<O, E2>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let state = &mut self.state;
+        let f = &mut self.f;
+        self.iter.next().map(|r| r.map_err(|e| f(state, e)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct WithErrorContext<I, F> {
+    iter: I,
+    f: F,
+    index: usize,
+}
+
+impl<I, O, E, F, E2> Iterator for WithErrorContext<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(usize, E) -> E2,
+{
+    type Item = Result<O, E2>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let res = self.iter.next()?;
+        let index = self.index;
+        self.index += 1;
+        Some(res.map_err(|e| (self.f)(index, e)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, O, E, F, E2> ExactSizeIterator for WithErrorContext<I, F>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(usize, E) -> E2,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MapErrToString<I> {
+    iter: I,
+}
+
+#[cfg(feature = "alloc")]
+impl<I, O, E> Iterator for MapErrToString<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E: Display,
+{
+    type Item = Result<O, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|r| r.map_err(|e| e.to_string()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, O, E> DoubleEndedIterator for MapErrToString<I>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    E: Display,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|r| r.map_err(|e| e.to_string()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, O, E> ExactSizeIterator for MapErrToString<I>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    E: Display,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,4 +536,82 @@ mod tests {
 
         assert_eq!(hint, (5, Some(5)));
     }
+
+    #[test]
+    fn test_map_ok_exact_size() {
+        let source = vec![Ok(1), Err("a"), Ok(3), Ok(4), Err("b")];
+        let mapped = source.clone().into_iter().map_ok(|i| 2 * i);
+        assert_eq!(mapped.len(), source.len());
+
+        let collected: Vec<_> = mapped.collect();
+        assert_eq!(collected.capacity(), source.len());
+    }
+
+    #[test]
+    fn test_map_err_exact_size() {
+        let source = vec![Ok(1), Err("a"), Ok(3), Ok(4), Err("b")];
+        let mapped = source.clone().into_iter().map_err(|e| e.len());
+        assert_eq!(mapped.len(), source.len());
+
+        let collected: Vec<_> = mapped.collect();
+        assert_eq!(collected.capacity(), source.len());
+    }
+
+    #[test]
+    fn test_map_ok_clone() {
+        let factor = 10;
+        let template = vec![Ok(1), Err("a"), Ok(3)]
+            .into_iter()
+            .map_ok(move |i| i * factor);
+
+        let copy = template.clone();
+        assert_eq!(template.collect::<Vec<_>>(), [Ok(10), Err("a"), Ok(30)]);
+        assert_eq!(copy.collect::<Vec<_>>(), [Ok(10), Err("a"), Ok(30)]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_map_err_to_string_rev() {
+        use std::str::FromStr;
+
+        let mut iter = ["1", "a"]
+            .iter()
+            .map(|txt| usize::from_str(txt))
+            .map_err_to_string();
+
+        assert_eq!(iter.len(), 2);
+        assert_eq!(
+            iter.next_back(),
+            Some(Err("invalid digit found in string".to_string()))
+        );
+        assert_eq!(iter.next_back(), Some(Ok(1)));
+    }
+
+    #[test]
+    fn test_map_ok_indexed_errors_do_not_count() {
+        let mut mapped = vec![Err("a"), Err("b"), Ok(10), Err("c"), Ok(20)]
+            .into_iter()
+            .map_ok_indexed(|i, x| i * 100 + x);
+
+        assert_eq!(mapped.len(), 5);
+        assert_eq!(mapped.next(), Some(Err("a")));
+        assert_eq!(mapped.next(), Some(Err("b")));
+        assert_eq!(mapped.next(), Some(Ok(10)));
+        assert_eq!(mapped.next(), Some(Err("c")));
+        assert_eq!(mapped.next(), Some(Ok(120)));
+        assert_eq!(mapped.next(), None);
+    }
+
+    #[test]
+    fn test_with_error_context_counts_all_items() {
+        let mapped: Vec<_> = vec![Err('a'), Ok(1), Ok(2), Err('b'), Err('c')]
+            .into_iter()
+            .with_error_context(|i, e| (i, e))
+            .collect();
+
+        assert_eq!(
+            mapped,
+            [Err((0, 'a')), Ok(1), Ok(2), Err((3, 'b')), Err((4, 'c'))]
+        );
+    }
 }
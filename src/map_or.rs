@@ -0,0 +1,166 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to transform both Oks and Errors
+/// into the same (non-`Result`) type.
+pub trait MapOr<O, E>: Sized {
+    /// Apply [`Result::map_or`] to every item: map the `Ok` values with the function
+    /// and replace every `Err` with the default value.
+    ///
+    /// The default value is cloned for every `Err`, hence the `Clone` bound.
+    /// Use [`map_ok_or_else`](MapOr::map_ok_or_else) to compute it instead.
+    ///
+    /// ```
+    /// use resiter::map_or::MapOr;
+    /// use std::str::FromStr;
+    ///
+    /// let mapped: Vec<_> = ["1", "2", "a", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .map_ok_or("none".to_owned(), |i| format!("#{}", i))
+    ///     .collect();
+    ///
+    /// assert_eq!(mapped, ["#1", "#2", "none", "#4"]);
+    /// ```
+    fn map_ok_or<U, F>(self, default: U, _: F) -> MapOkOr<Self, U, F>
+    where
+        U: Clone,
+        F: FnMut(O) -> U;
+
+    /// Apply [`Result::map_or_else`] to every item: map the `Ok` values with the function `f`
+    /// and the `Err` values with the function `default`.
+    ///
+    /// ```
+    /// use resiter::map_or::MapOr;
+    /// use std::str::FromStr;
+    ///
+    /// let mapped: Vec<_> = ["1", "2", "a", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .map_ok_or_else(|e| format!("error: {}", e), |i| format!("#{}", i))
+    ///     .collect();
+    ///
+    /// assert_eq!(mapped, ["#1", "#2", "error: invalid digit found in string", "#4"]);
+    /// ```
+    fn map_ok_or_else<U, D, F>(self, default: D, _: F) -> MapOkOrElse<Self, D, F>
+    where
+        D: FnMut(E) -> U,
+        F: FnMut(O) -> U;
+}
+
+impl<I, O, E> MapOr<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn map_ok_or<U, F>(self, default: U, f: F) -> MapOkOr<Self, U, F>
+    where
+        U: Clone,
+        F: FnMut(O) -> U,
+    {
+        MapOkOr {
+            iter: self,
+            default,
+            f,
+        }
+    }
+
+    #[inline]
+    fn map_ok_or_else<U, D, F>(self, default: D, f: F) -> MapOkOrElse<Self, D, F>
+    where
+        D: FnMut(E) -> U,
+        F: FnMut(O) -> U,
+    {
+        MapOkOrElse {
+            iter: self,
+            default,
+            f,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MapOkOr<I, U, F> {
+    iter: I,
+    default: U,
+    f: F,
+}
+
+impl<I, O, E, U, F> Iterator for MapOkOr<I, U, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    U: Clone,
+    F: FnMut(O) -> U,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|r| match r {
+            Ok(o) => (self.f)(o),
+            Err(_) => self.default.clone(),
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MapOkOrElse<I, D, F> {
+    iter: I,
+    default: D,
+    f: F,
+}
+
+impl<I, O, E, U, D, F> Iterator for MapOkOrElse<I, D, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    D: FnMut(E) -> U,
+    F: FnMut(O) -> U,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|r| match r {
+            Ok(o) => (self.f)(o),
+            Err(e) => (self.default)(e),
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_ok_or() {
+        let mapped: Vec<_> = vec![Ok(1), Err("a"), Ok(3), Err("b")]
+            .into_iter()
+            .map_ok_or(0, |i| i * 10)
+            .collect();
+
+        assert_eq!(mapped, [10, 0, 30, 0]);
+    }
+
+    #[test]
+    fn test_map_ok_or_else() {
+        let mapped: Vec<_> = vec![Ok(1), Err("a"), Ok(3), Err("bcd")]
+            .into_iter()
+            .map_ok_or_else(|e| e.len(), |i| i * 10)
+            .collect();
+
+        assert_eq!(mapped, [10, 1, 30, 3]);
+    }
+}
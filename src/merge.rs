@@ -0,0 +1,128 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#[cfg(not(test))]
+use core::iter::Fuse;
+#[cfg(test)]
+use std::iter::Fuse;
+
+/// Merge two `Iterator<Item = Result<T, E>>` sorted by their `Ok` values
+/// into a single one, also sorted by the `Ok` values in ascending order.
+///
+/// The `Err` values are forwarded eagerly, as soon as they are encountered:
+/// before comparing the next pair of `Ok` values, both of the sides are advanced
+/// until they produce an `Ok` (or are exhausted), and every `Err` found
+/// on the way is yielded immediately, the errors from `a` going first.
+///
+/// If the `Ok` values from both sides are equal, the one from `a` is yielded first.
+///
+/// ```
+/// use resiter::merge::merge_ok_sorted;
+///
+/// let a = vec![Ok(1), Ok(4), Ok(6)];
+/// let b = vec![Ok(2), Err("b"), Ok(4), Ok(5)];
+///
+/// let merged: Vec<_> = merge_ok_sorted(a, b).collect();
+/// assert_eq!(merged, [Ok(1), Ok(2), Err("b"), Ok(4), Ok(4), Ok(5), Ok(6)]);
+/// ```
+pub fn merge_ok_sorted<I, J, T, E>(a: I, b: J) -> MergeOkSorted<I::IntoIter, J::IntoIter, T>
+where
+    I: IntoIterator<Item = Result<T, E>>,
+    J: IntoIterator<Item = Result<T, E>>,
+    T: Ord,
+{
+    MergeOkSorted {
+        a: a.into_iter().fuse(),
+        b: b.into_iter().fuse(),
+        a_head: None,
+        b_head: None,
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MergeOkSorted<I, J, T> {
+    a: Fuse<I>,
+    b: Fuse<J>,
+    a_head: Option<T>,
+    b_head: Option<T>,
+}
+
+impl<I, J, T, E> Iterator for MergeOkSorted<I, J, T>
+where
+    I: Iterator<Item = Result<T, E>>,
+    J: Iterator<Item = Result<T, E>>,
+    T: Ord,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.a_head.is_none() {
+            match self.a.next() {
+                Some(Ok(x)) => self.a_head = Some(x),
+                Some(Err(e)) => return Some(Err(e)),
+                None => {}
+            }
+        }
+        if self.b_head.is_none() {
+            match self.b.next() {
+                Some(Ok(x)) => self.b_head = Some(x),
+                Some(Err(e)) => return Some(Err(e)),
+                None => {}
+            }
+        }
+
+        let take_a = match (&self.a_head, &self.b_head) {
+            (Some(a), Some(b)) => a <= b,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => return None,
+        };
+        if take_a {
+            self.a_head.take().map(Ok)
+        } else {
+            self.b_head.take().map(Ok)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let heads = usize::from(self.a_head.is_some()) + usize::from(self.b_head.is_some());
+        let (a_lo, a_hi) = self.a.size_hint();
+        let (b_lo, b_hi) = self.b.size_hint();
+        let lo = a_lo.saturating_add(b_lo).saturating_add(heads);
+        let hi = match (a_hi, b_hi) {
+            (Some(a), Some(b)) => a.checked_add(b).and_then(|hi| hi.checked_add(heads)),
+            _ => None,
+        };
+        (lo, hi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_ok_sorted_with_error() {
+        let a = vec![Ok(1), Ok(3), Err("a"), Ok(5), Ok(7)];
+        let b = vec![Ok(2), Ok(3), Ok(8)];
+
+        let merged = merge_ok_sorted(a, b);
+        assert_eq!(merged.size_hint(), (8, Some(8)));
+
+        assert_eq!(
+            merged.collect::<Vec<_>>(),
+            [Ok(1), Ok(2), Ok(3), Err("a"), Ok(3), Ok(5), Ok(7), Ok(8)]
+        );
+    }
+
+    #[test]
+    fn test_merge_ok_sorted_one_side_empty() {
+        let merged: Vec<_> = merge_ok_sorted(vec![], vec![Ok(1), Err("b"), Ok(2)]).collect();
+
+        assert_eq!(merged, [Ok(1), Err("b"), Ok(2)]);
+    }
+}
@@ -88,6 +88,16 @@ where
     E: Sized,
     F: Fn() -> E;
 
+impl<I, T, E, F> Clone for IterInnerOkOrElseImpl<I, T, E, F>
+where
+    I: Iterator<Item = Result<Option<T>, E>> + Clone,
+    F: Fn() -> E + Clone,
+{
+    fn clone(&self) -> Self {
+        IterInnerOkOrElseImpl(self.0.clone(), self.1.clone())
+    }
+}
+
 impl<I, T, E, F> IterInnerOkOrElse<T, E, F> for I
 where
     I: Iterator<Item = Result<Option<T>, E>> + Sized,
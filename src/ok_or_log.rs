@@ -0,0 +1,129 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#[cfg(not(test))]
+use core::fmt::Display;
+#[cfg(test)]
+use std::fmt::Display;
+
+use log::Level;
+
+/// Extension trait for `Iterator<Item = Result<T, E>>` to log every `Err` and only keep the `T`s
+pub trait OkOrLogExt<O, E>: Sized {
+    /// Log every `Err` with the given [`Level`] and drop it, yielding only the `Ok` values
+    ///
+    /// ```
+    /// use resiter::ok_or_log::OkOrLogExt;
+    /// use std::str::FromStr;
+    ///
+    /// let parsed: Vec<usize> = ["1", "2", "a", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .ok_or_log(log::Level::Warn) // logs "invalid digit found in string"
+    ///     .collect();
+    ///
+    /// assert_eq!(parsed, [1, 2, 4]);
+    /// ```
+    fn ok_or_log(self, level: Level) -> OkOrLog<Self>
+    where
+        E: Display;
+}
+
+impl<I, O, E> OkOrLogExt<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    #[inline]
+    fn ok_or_log(self, level: Level) -> OkOrLog<Self>
+    where
+        E: Display,
+    {
+        OkOrLog { iter: self, level }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct OkOrLog<I> {
+    iter: I,
+    level: Level,
+}
+
+impl<I, O, E> Iterator for OkOrLog<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E: Display,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(o) => return Some(o),
+                Err(e) => log::log!(self.level, "{}", e),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use log::{LevelFilter, Log, Metadata, Record};
+    use std::cell::RefCell;
+
+    thread_local! {
+        static RECORDS: RefCell<Vec<(Level, String)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    struct CapturingLogger;
+
+    impl Log for CapturingLogger {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record) {
+            RECORDS.with(|records| {
+                records
+                    .borrow_mut()
+                    .push((record.level(), record.args().to_string()))
+            });
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn test_ok_or_log_captures_errors() {
+        static LOGGER: CapturingLogger = CapturingLogger;
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(LevelFilter::Trace);
+
+        let oks: Vec<_> = vec![Ok(1), Err("a"), Ok(3), Err("b")]
+            .into_iter()
+            .ok_or_log(Level::Error)
+            .collect();
+
+        assert_eq!(oks, [1, 3]);
+        RECORDS.with(|records| {
+            assert_eq!(
+                *records.borrow(),
+                [
+                    (Level::Error, "a".to_owned()),
+                    (Level::Error, "b".to_owned())
+                ]
+            )
+        });
+    }
+}
@@ -19,6 +19,10 @@ pub use util::Process as Oks;
 pub trait GetOks<T, E>: Sized {
     /// Iterate over every `Ok` while ignoring every `Err`
     ///
+    /// The returned iterator is lazy, only keeps the upper bound of the
+    /// [`size_hint`](Iterator::size_hint) and is [fused](core::iter::FusedIterator)
+    /// whenever the underlying iterator is.
+    ///
     /// ```
     /// use std::str::FromStr;
     /// use resiter::oks::GetOks;
@@ -35,6 +39,48 @@ pub trait GetOks<T, E>: Sized {
     /// );
     /// ```
     fn oks(self) -> FilterMap<Self, fn(Result<T, E>) -> Option<T>>;
+
+    /// Iterate over every `Ok` along with its position, while ignoring every `Err`
+    ///
+    /// The index counts every item (both `Ok` and `Err`),
+    /// same as in [`errors_with_index`](crate::errors::GetErrors::errors_with_index),
+    /// so it matches the position of the value in the original iterator.
+    ///
+    /// ```
+    /// use resiter::oks::GetOks;
+    ///
+    /// let oks: Vec<_> = vec![Ok(1), Err("a"), Ok(3)]
+    ///     .into_iter()
+    ///     .oks_with_index()
+    ///     .collect();
+    ///
+    /// assert_eq!(oks, [(0, 1), (2, 3)]);
+    /// ```
+    fn oks_with_index(self) -> OksWithIndex<Self>;
+
+    /// Iterate over every `Ok`, calling `f` on every `Err` before dropping it
+    ///
+    /// Same as [`oks`](GetOks::oks), but the errors are not lost silently:
+    /// this is a more flexible sibling of the `ok_or_log` adapter
+    /// (available with the `log` feature).
+    ///
+    /// ```
+    /// use resiter::oks::GetOks;
+    /// use std::str::FromStr;
+    ///
+    /// let mut dropped = Vec::new();
+    /// let parsed: Vec<usize> = ["1", "a", "3", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|_| *txt))
+    ///     .tap_errors(|e| dropped.push(*e))
+    ///     .collect();
+    ///
+    /// assert_eq!(parsed, [1, 3]);
+    /// assert_eq!(dropped, ["a", "b"]);
+    /// ```
+    fn tap_errors<F>(self, f: F) -> TapErrors<Self, F>
+    where
+        F: FnMut(&E);
 }
 
 impl<T, E, I> GetOks<T, E> for I
@@ -46,4 +92,158 @@ where
     fn oks(self) -> FilterMap<Self, fn(Result<T, E>) -> Option<T>> {
         self.filter_map(GetOk::get_ok)
     }
+
+    #[inline]
+    fn oks_with_index(self) -> OksWithIndex<Self> {
+        OksWithIndex {
+            iter: self,
+            index: 0,
+        }
+    }
+
+    #[inline]
+    fn tap_errors<F>(self, f: F) -> TapErrors<Self, F>
+    where
+        F: FnMut(&E),
+    {
+        TapErrors { iter: self, f }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct OksWithIndex<I> {
+    iter: I,
+    index: usize,
+}
+
+impl<I, T, E> Iterator for OksWithIndex<I>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = (usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let res = self.iter.next()?;
+            let index = self.index;
+            self.index += 1;
+            if let Ok(t) = res {
+                return Some((index, t));
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct TapErrors<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, T, E, F> Iterator for TapErrors<I, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(&E),
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(t) => return Some(t),
+                Err(e) => (self.f)(&e),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oks_hint() {
+        use std::str::FromStr;
+
+        let hint = ["1", "2", "a", "4", "5"]
+            .iter()
+            .map(|txt| usize::from_str(txt))
+            .oks()
+            .size_hint();
+
+        assert_eq!(hint, (0, Some(5)));
+    }
+
+    #[test]
+    fn test_oks_fused() {
+        fn assert_fused<I: FusedIterator>(_: &I) {}
+
+        let mut oks = vec![Ok(1), Err("a"), Ok(3)].into_iter().oks();
+        assert_fused(&oks);
+
+        assert_eq!(oks.next(), Some(1));
+        assert_eq!(oks.next(), Some(3));
+        assert_eq!(oks.next(), None);
+        assert_eq!(oks.next(), None);
+    }
+
+    #[test]
+    fn test_oks_with_index_match_source() {
+        use errors::GetErrors;
+
+        let source = vec![Err("a"), Ok(1), Ok(2), Err("b"), Ok(3), Err("c")];
+
+        let oks: Vec<_> = source.clone().into_iter().oks_with_index().collect();
+        let errors: Vec<_> = source.clone().into_iter().errors_with_index().collect();
+        assert_eq!(oks, [(1, 1), (2, 2), (4, 3)]);
+        assert_eq!(errors, [(0, "a"), (3, "b"), (5, "c")]);
+
+        for (i, ok) in oks {
+            assert_eq!(source[i], Ok(ok));
+        }
+        for (i, e) in errors {
+            assert_eq!(source[i], Err(e));
+        }
+    }
+
+    #[test]
+    fn test_tap_errors_is_lazy() {
+        let mut tapped = 0;
+        {
+            let mut oks = vec![Err("a"), Ok(1), Err("b"), Ok(2)]
+                .into_iter()
+                .tap_errors(|_| tapped += 1);
+
+            assert_eq!(oks.next(), Some(1));
+        }
+        assert_eq!(tapped, 1);
+    }
+
+    #[test]
+    fn test_oks_adapters_hint() {
+        let source = vec![Ok(1), Err("a"), Ok(2), Err("b"), Ok(3)];
+
+        assert_eq!(
+            source.clone().into_iter().oks_with_index().size_hint(),
+            (0, Some(5))
+        );
+        assert_eq!(
+            source.into_iter().tap_errors(|_| {}).size_hint(),
+            (0, Some(5))
+        );
+    }
 }
@@ -10,6 +10,16 @@ where
     I: Iterator<Item = Result<O, E>>,
     F: FnMut(&E);
 
+impl<I, O, E, F> Clone for OnErr<I, O, E, F>
+where
+    I: Iterator<Item = Result<O, E>> + Clone,
+    F: FnMut(&E) + Clone,
+{
+    fn clone(&self) -> Self {
+        OnErr(self.0.clone(), self.1.clone())
+    }
+}
+
 /// Extension trait for `Iterator<Item = Result<T, E>>` to do something on `Err(_)`
 pub trait OnErrDo<I, O, E, F>
 where
@@ -33,6 +43,9 @@ where
     ///
     /// assert_eq!(errs.len(), 2);
     /// ```
+    ///
+    /// See also [`Inspect::inspect_err`][crate::inspect::Inspect::inspect_err] which also keeps
+    /// the [`size_hint`](Iterator::size_hint) of the underlying iterator.
     fn on_err(self, _: F) -> OnErr<I, O, E, F>;
 }
 
@@ -56,10 +69,10 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         self.0.next().map(|r| {
-            r.map_err(|e| {
-                (self.1)(&e);
-                e
-            })
+            if let Err(ref e) = r {
+                (self.1)(e);
+            }
+            r
         })
     }
 }
@@ -10,6 +10,16 @@ where
     I: Iterator<Item = Result<O, E>>,
     F: FnMut(&O);
 
+impl<I, O, E, F> Clone for OnOk<I, O, E, F>
+where
+    I: Iterator<Item = Result<O, E>> + Clone,
+    F: FnMut(&O) + Clone,
+{
+    fn clone(&self) -> Self {
+        OnOk(self.0.clone(), self.1.clone())
+    }
+}
+
 /// Extension trait for `Iterator<Item = Result<T, E>>` to do something on `Ok(_)`
 pub trait OnOkDo<I, O, E, F>
 where
@@ -31,6 +41,9 @@ where
     ///
     /// assert_eq!(oks, vec![1, 2, 5]);
     /// ```
+    ///
+    /// See also [`Inspect::inspect_ok`][crate::inspect::Inspect::inspect_ok] which also keeps
+    /// the [`size_hint`](Iterator::size_hint) of the underlying iterator.
     fn on_ok(self, _: F) -> OnOk<I, O, E, F>;
 }
 
@@ -54,10 +67,100 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         self.0.next().map(|r| {
-            r.map(|o| {
-                (self.1)(&o);
-                o
-            })
+            if let Ok(ref o) = r {
+                (self.1)(o);
+            }
+            r
         })
     }
 }
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct OnOkMut<I, O, E, S, F>(I, S, F)
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&mut S, &O);
+
+impl<I, O, E, S, F> OnOkMut<I, O, E, S, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&mut S, &O),
+{
+    /// Get the state back, dropping the rest of the iterator
+    pub fn into_state(self) -> S {
+        self.1
+    }
+}
+
+impl<I, O, E, S, F> Clone for OnOkMut<I, O, E, S, F>
+where
+    I: Iterator<Item = Result<O, E>> + Clone,
+    S: Clone,
+    F: FnMut(&mut S, &O) + Clone,
+{
+    fn clone(&self) -> Self {
+        OnOkMut(self.0.clone(), self.1.clone(), self.2.clone())
+    }
+}
+
+/// Extension trait for `Iterator<Item = Result<T, E>>` to do something on `Ok(_)`
+/// with the access to the mutable state
+pub trait OnOkMutDo<I, O, E, S, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&mut S, &O),
+{
+    /// Perform a side effect on each Ok value, updating the state owned by the adapter
+    ///
+    /// The state could be retrieved with the [`into_state`](OnOkMut::into_state)
+    /// after the iteration.
+    ///
+    /// ```
+    /// use resiter::onok::OnOkMutDo;
+    /// use std::str::FromStr;
+    ///
+    /// let mut iter = ["1", "2", "a", "b", "5"]
+    ///     .iter()
+    ///     .map(|e| usize::from_str(e))
+    ///     .on_ok_mut(0, |sum, i| *sum += i);
+    ///
+    /// assert_eq!(iter.by_ref().count(), 5);
+    /// assert_eq!(iter.into_state(), 8);
+    /// ```
+    fn on_ok_mut(self, state: S, _: F) -> OnOkMut<I, O, E, S, F>;
+}
+
+impl<I, O, E, S, F> OnOkMutDo<I, O, E, S, F> for I
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&mut S, &O),
+{
+    #[inline]
+    fn on_ok_mut(self, state: S, f: F) -> OnOkMut<I, O, E, S, F> {
+        OnOkMut(self, state, f)
+    }
+}
+
+impl<I, O, E, S, F> Iterator for OnOkMut<I, O, E, S, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&mut S, &O),
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let state = &mut self.1;
+        let f = &mut self.2;
+        self.0.next().map(|r| {
+            if let Ok(ref o) = r {
+                f(state, o);
+            }
+            r
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
@@ -0,0 +1,131 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to look at the next item
+/// without consuming it.
+pub trait Peekable<O, E>: Sized {
+    /// Create an iterator which allows to [`peek_ok`](PeekableOk::peek_ok)
+    /// at the next item in the form of `Result<&O, &E>`.
+    ///
+    /// ```
+    /// use resiter::peekable::Peekable;
+    ///
+    /// let mut iter = vec![Ok(1), Err("a"), Ok(3)].into_iter().peekable_ok();
+    ///
+    /// assert_eq!(iter.peek_ok(), Some(Ok(&1)));
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    ///
+    /// assert_eq!(iter.peek_ok(), Some(Err(&"a")));
+    /// assert_eq!(iter.peek_ok(), Some(Err(&"a")));
+    /// assert_eq!(iter.next(), Some(Err("a")));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// assert_eq!(iter.peek_ok(), None);
+    /// ```
+    fn peekable_ok(self) -> PeekableOk<Self>
+    where
+        Self: Iterator;
+}
+
+impl<I, O, E> Peekable<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn peekable_ok(self) -> PeekableOk<Self> {
+        PeekableOk {
+            iter: self,
+            peeked: None,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct PeekableOk<I: Iterator> {
+    iter: I,
+    /// Remember a peeked item, even if it was `None`.
+    peeked: Option<Option<I::Item>>,
+}
+
+impl<I> Clone for PeekableOk<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        PeekableOk {
+            iter: self.iter.clone(),
+            peeked: self.peeked.clone(),
+        }
+    }
+}
+
+impl<I, O, E> PeekableOk<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    /// Return a reference to the next item without advancing the iterator.
+    ///
+    /// At most one item is buffered, so nothing is ever skipped or duplicated:
+    /// if the next item is an `Err`, it is returned here as `Err(&e)`
+    /// and then by the following call to [`next`](Iterator::next) unchanged.
+    pub fn peek_ok(&mut self) -> Option<Result<&O, &E>> {
+        let iter = &mut self.iter;
+        self.peeked
+            .get_or_insert_with(|| iter.next())
+            .as_ref()
+            .map(Result::as_ref)
+    }
+}
+
+impl<I, O, E> Iterator for PeekableOk<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => self.iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = match self.peeked {
+            Some(None) => return (0, Some(0)),
+            Some(Some(_)) => 1,
+            None => 0,
+        };
+        let (lo, hi) = self.iter.size_hint();
+        (
+            lo.saturating_add(peeked),
+            hi.and_then(|hi| hi.checked_add(peeked)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peek_ok_then_err() {
+        let mut iter = vec![Ok(1), Err("a")].into_iter().peekable_ok();
+
+        assert_eq!(iter.peek_ok(), Some(Ok(&1)));
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next(), Some(Ok(1)));
+
+        assert_eq!(iter.peek_ok(), Some(Err(&"a")));
+        assert_eq!(iter.next(), Some(Err("a")));
+
+        assert_eq!(iter.peek_ok(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -6,23 +6,88 @@
 
 //! Prelude
 //!
-//! Imports all things publicly, so you can `use resiter::prelude::*;` in your crate.
+//! Imports all the extension traits publicly, so you can `use resiter::prelude::*;` in your crate
+//! to get all the methods on the iterators of `Result`s.
+//!
+//! Only the traits are exported: the adapter structs they return,
+//! the free functions (like [`merge_ok_sorted`](crate::merge::merge_ok_sorted))
+//! and the helper traits used by the implementation ([`GetOk`](crate::GetOk),
+//! [`GetErr`](crate::GetErr), [`Process`](crate::Process)) are left in their modules.
+//!
+//! ```
+//! use resiter::prelude::*;
+//! use std::str::FromStr;
+//!
+//! let sum = ["1", "2", "a", "4"]
+//!     .iter()
+//!     .map(|txt| usize::from_str(txt))
+//!     .map_err(|e| e.to_string())
+//!     .on_err(|e| println!("Skipping: {}", e))
+//!     .oks()
+//!     .sum::<usize>();
+//!
+//! assert_eq!(sum, 7);
+//! ```
 //!
 
-pub use and_then::*;
-pub use errors::*;
-pub use filter::*;
-pub use filter_map::*;
-pub use flat_map::*;
-pub use flatten::*;
-pub use map::*;
-pub use ok_or_else::*;
-pub use oks::*;
-pub use onerr::*;
-pub use onok::*;
-pub use try_filter::*;
-pub use try_filter_map::*;
-pub use try_map::*;
-pub use unit::*;
-pub use unwrap::*;
-pub use while_ok::*;
+pub use and_then::AndThen;
+pub use assert::Assert;
+#[cfg(feature = "alloc")]
+pub use boxed::Boxed;
+#[cfg(feature = "alloc")]
+pub use chunks::Chunks;
+pub use coalesce::Coalesce;
+#[cfg(feature = "alloc")]
+pub use collect::CollectResult;
+pub use collect::TryCollect;
+#[cfg(feature = "alloc")]
+pub use collect::UnzipResults;
+pub use count::Count;
+#[cfg(feature = "std")]
+pub use deadline::Deadline;
+pub use dedup::Dedup;
+#[cfg(feature = "alloc")]
+pub use defer::Defer;
+pub use enumerate::Enumerate;
+pub use errors::GetErrors;
+#[cfg(feature = "fallible-iterator")]
+pub use fallible::IntoFallibleExt;
+pub use filter::Filter;
+pub use filter_map::FilterMap;
+pub use find::Find;
+pub use flat_map::FlatMap;
+pub use flatten::{Flatten, FlattenOptionsExt};
+pub use fold::FoldOk;
+pub use fuse::Fuse;
+pub use inspect::Inspect;
+pub use interleave::Interleave;
+pub use into_oks::IntoOksExt;
+pub use map::Map;
+pub use map_or::MapOr;
+pub use ok_or_else::{IterInnerOkOrElse, ResultOptionExt};
+#[cfg(feature = "log")]
+pub use ok_or_log::OkOrLogExt;
+pub use oks::GetOks;
+pub use onerr::OnErrDo;
+pub use onok::{OnOkDo, OnOkMutDo};
+pub use peekable::Peekable;
+pub use reduce::Reduce;
+pub use retry::Retry;
+pub use scan::Scan;
+pub use skip::Skip;
+pub use skip_while::SkipWhile;
+#[cfg(feature = "alloc")]
+pub use split::Split;
+#[cfg(feature = "stream")]
+pub use stream::TryStreamResiterExt;
+pub use take::Take;
+pub use take_while::TakeWhile;
+pub use throttle::Throttle;
+pub use try_filter::TryFilter;
+pub use try_filter_map::TryFilterMap;
+pub use try_map::TryMap;
+pub use unit::FoldUnit;
+pub use until_ok::UntilOk;
+pub use unwrap::{UnwrapOkOrDefaultExt, UnwrapWithExt};
+pub use while_ok::WhileOk;
+pub use zip::Zip;
@@ -0,0 +1,461 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#[cfg(not(test))]
+use core::{
+    hash::{Hash, Hasher},
+    iter::{Product, Sum},
+};
+#[cfg(test)]
+use std::{
+    hash::{Hash, Hasher},
+    iter::{Product, Sum},
+};
+
+use util::try_process_oks;
+
+/// Extension trait for `Iterator<Item = Result<T, E>>` to reduce all the `Ok` values
+/// into a single one, failing on the first `Err`.
+pub trait Reduce<T, E>: Sized {
+    /// Sum all the `Ok` values, stopping on the first [`Err`] and returning it instead.
+    ///
+    /// ```
+    /// use resiter::reduce::Reduce;
+    ///
+    /// let sum: Result<i32, &str> = vec![Ok(1), Ok(2), Ok(3)].into_iter().sum_ok();
+    /// assert_eq!(sum, Ok(6));
+    ///
+    /// let sum: Result<i32, _> = vec![Ok(1), Ok(2), Err("boom"), Ok(3)].into_iter().sum_ok();
+    /// assert_eq!(sum, Err("boom"));
+    /// ```
+    fn sum_ok<S>(self) -> Result<S, E>
+    where
+        S: Sum<T>;
+
+    /// Multiply all the `Ok` values, stopping on the first [`Err`] and returning it instead.
+    ///
+    /// ```
+    /// use resiter::reduce::Reduce;
+    ///
+    /// let product: Result<i32, &str> = vec![Ok(2), Ok(3), Ok(4)].into_iter().product_ok();
+    /// assert_eq!(product, Ok(24));
+    ///
+    /// let mut iter = vec![Ok(2), Err("boom"), Ok(4)].into_iter();
+    /// let product: Result<i32, _> = iter.by_ref().product_ok();
+    /// assert_eq!(product, Err("boom"));
+    /// assert_eq!(iter.next(), Some(Ok(4)));
+    /// ```
+    fn product_ok<P>(self) -> Result<P, E>
+    where
+        P: Product<T>;
+
+    /// Find the maximum of all the `Ok` values, stopping on the first [`Err`]
+    /// and returning it instead.
+    ///
+    /// If several elements are equally maximum, the last one is returned.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    ///
+    /// ```
+    /// use resiter::reduce::Reduce;
+    ///
+    /// let max = vec![Ok(2), Ok(5), Ok(3)].into_iter().max_ok();
+    /// assert_eq!(max, Ok::<_, &str>(Some(5)));
+    ///
+    /// let max = vec![Ok(2), Err("boom"), Ok(5)].into_iter().max_ok();
+    /// assert_eq!(max, Err("boom"));
+    /// ```
+    fn max_ok(self) -> Result<Option<T>, E>
+    where
+        T: Ord;
+
+    /// Find the minimum of all the `Ok` values, stopping on the first [`Err`]
+    /// and returning it instead.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    ///
+    /// ```
+    /// use resiter::reduce::Reduce;
+    ///
+    /// let min = vec![Ok(2), Ok(5), Ok(3)].into_iter().min_ok();
+    /// assert_eq!(min, Ok::<_, &str>(Some(2)));
+    ///
+    /// let min = vec![Ok(2), Err("boom"), Ok(1)].into_iter().min_ok();
+    /// assert_eq!(min, Err("boom"));
+    /// ```
+    fn min_ok(self) -> Result<Option<T>, E>
+    where
+        T: Ord;
+
+    /// Find the `Ok` value that gives the maximum value from the specified function,
+    /// stopping on the first [`Err`] and returning it instead.
+    ///
+    /// If several elements are equally maximum, the last one is returned.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    ///
+    /// ```
+    /// use resiter::reduce::Reduce;
+    ///
+    /// let longest = vec![Ok("a"), Ok("abc"), Ok("ab")]
+    ///     .into_iter()
+    ///     .max_ok_by_key(|s| s.len());
+    /// assert_eq!(longest, Ok::<_, ()>(Some("abc")));
+    /// ```
+    fn max_ok_by_key<K, F>(self, f: F) -> Result<Option<T>, E>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Find the `Ok` value that gives the minimum value from the specified function,
+    /// stopping on the first [`Err`] and returning it instead.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    ///
+    /// ```
+    /// use resiter::reduce::Reduce;
+    ///
+    /// let shortest = vec![Ok("abc"), Ok("a"), Ok("ab")]
+    ///     .into_iter()
+    ///     .min_ok_by_key(|s| s.len());
+    /// assert_eq!(shortest, Ok::<_, ()>(Some("a")));
+    /// ```
+    fn min_ok_by_key<K, F>(self, f: F) -> Result<Option<T>, E>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// [Reduce](Iterator::reduce) the `Ok` values to a single one by repeatedly applying
+    /// the reducing function, stopping on the first [`Err`] and returning it instead.
+    ///
+    /// The first `Ok` value is used as the initial accumulator.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    ///
+    /// ```
+    /// use resiter::reduce::Reduce;
+    ///
+    /// let max = vec![Ok(2), Ok(5), Ok(3)].into_iter().reduce_ok(|a, b| a.max(b));
+    /// assert_eq!(max, Ok::<_, &str>(Some(5)));
+    ///
+    /// let mut iter = vec![Err("boom"), Ok(5), Ok(3)].into_iter();
+    /// assert_eq!(iter.reduce_ok(|a, b| a + b), Err("boom"));
+    /// assert_eq!(iter.reduce_ok(|a, b| a + b), Ok(Some(8)));
+    /// ```
+    fn reduce_ok<F>(&mut self, f: F) -> Result<Option<T>, E>
+    where
+        F: FnMut(T, T) -> T;
+
+    /// Find the `Ok` value that gives the maximum value from the specified fallible function,
+    /// stopping on the first [`Err`] either from the iterator or from the function,
+    /// and returning it instead.
+    ///
+    /// If several elements are equally maximum, the last one is returned.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    ///
+    /// ```
+    /// use resiter::reduce::Reduce;
+    /// use std::str::FromStr;
+    ///
+    /// let score = |txt: &&str| usize::from_str(txt).map_err(|_| "invalid score");
+    ///
+    /// let best = vec![Ok("3"), Ok("7"), Ok("5")].into_iter().try_max_by_key(score);
+    /// assert_eq!(best, Ok(Some("7")));
+    ///
+    /// let best = vec![Ok("3"), Ok("x"), Ok("5")].into_iter().try_max_by_key(score);
+    /// assert_eq!(best, Err("invalid score"));
+    /// ```
+    fn try_max_by_key<K, F>(&mut self, f: F) -> Result<Option<T>, E>
+    where
+        K: Ord,
+        F: FnMut(&T) -> Result<K, E>;
+
+    /// Find the `Ok` value that gives the minimum value from the specified fallible function,
+    /// stopping on the first [`Err`] either from the iterator or from the function,
+    /// and returning it instead.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    ///
+    /// ```
+    /// use resiter::reduce::Reduce;
+    /// use std::str::FromStr;
+    ///
+    /// let score = |txt: &&str| usize::from_str(txt).map_err(|_| "invalid score");
+    ///
+    /// let worst = vec![Ok("3"), Ok("1"), Ok("5")].into_iter().try_min_by_key(score);
+    /// assert_eq!(worst, Ok(Some("1")));
+    ///
+    /// let worst = vec![Ok("3"), Err("io error"), Ok("x")].into_iter().try_min_by_key(score);
+    /// assert_eq!(worst, Err("io error"));
+    /// ```
+    fn try_min_by_key<K, F>(&mut self, f: F) -> Result<Option<T>, E>
+    where
+        K: Ord,
+        F: FnMut(&T) -> Result<K, E>;
+
+    /// [Hash](Hash::hash) all the `Ok` values one after another into a single hasher
+    /// and return its [`finish`](Hasher::finish), stopping on the first [`Err`]
+    /// and returning it instead.
+    ///
+    /// The hasher is created with `make_hasher`, so two streams can be compared
+    /// by their checksums only if `make_hasher` produces the same hashers for both of them
+    /// (e.g. `DefaultHasher::new`, but not a fresh `RandomState` every time).
+    ///
+    /// The checksum depends on the order of the values:
+    /// the same values in a different order most probably give a different checksum.
+    ///
+    /// ```
+    /// use resiter::reduce::Reduce;
+    /// use std::collections::hash_map::DefaultHasher;
+    ///
+    /// let a: Result<_, ()> = vec![Ok("x"), Ok("y")].into_iter().checksum_ok(DefaultHasher::new);
+    /// let b = vec![Ok("x"), Ok("y")].into_iter().checksum_ok(DefaultHasher::new);
+    /// let reversed = vec![Ok("y"), Ok("x")].into_iter().checksum_ok(DefaultHasher::new);
+    /// assert_eq!(a, b);
+    /// assert_ne!(a, reversed);
+    ///
+    /// let failed = vec![Ok("x"), Err(1), Ok("y")].into_iter().checksum_ok(DefaultHasher::new);
+    /// assert_eq!(failed, Err(1));
+    /// ```
+    fn checksum_ok<H, F>(self, make_hasher: F) -> Result<u64, E>
+    where
+        T: Hash,
+        H: Hasher,
+        F: FnMut() -> H;
+}
+
+impl<I, T, E> Reduce<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    #[inline]
+    fn sum_ok<S>(self) -> Result<S, E>
+    where
+        S: Sum<T>,
+    {
+        self.sum()
+    }
+
+    #[inline]
+    fn product_ok<P>(self) -> Result<P, E>
+    where
+        P: Product<T>,
+    {
+        self.product()
+    }
+
+    #[inline]
+    fn max_ok(self) -> Result<Option<T>, E>
+    where
+        T: Ord,
+    {
+        try_process_oks(self, |oks| oks.max())
+    }
+
+    #[inline]
+    fn min_ok(self) -> Result<Option<T>, E>
+    where
+        T: Ord,
+    {
+        try_process_oks(self, |oks| oks.min())
+    }
+
+    #[inline]
+    fn max_ok_by_key<K, F>(self, f: F) -> Result<Option<T>, E>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        try_process_oks(self, |oks| oks.max_by_key(f))
+    }
+
+    #[inline]
+    fn min_ok_by_key<K, F>(self, f: F) -> Result<Option<T>, E>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        try_process_oks(self, |oks| oks.min_by_key(f))
+    }
+
+    #[inline]
+    fn reduce_ok<F>(&mut self, f: F) -> Result<Option<T>, E>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        try_process_oks(self, |oks| oks.reduce(f))
+    }
+
+    fn try_max_by_key<K, F>(&mut self, mut f: F) -> Result<Option<T>, E>
+    where
+        K: Ord,
+        F: FnMut(&T) -> Result<K, E>,
+    {
+        let mut best: Option<(K, T)> = None;
+        for res in self {
+            let x = res?;
+            let key = f(&x)?;
+            match best {
+                Some((ref best_key, _)) if key < *best_key => {}
+                _ => best = Some((key, x)),
+            }
+        }
+        Ok(best.map(|(_, x)| x))
+    }
+
+    fn try_min_by_key<K, F>(&mut self, mut f: F) -> Result<Option<T>, E>
+    where
+        K: Ord,
+        F: FnMut(&T) -> Result<K, E>,
+    {
+        let mut best: Option<(K, T)> = None;
+        for res in self {
+            let x = res?;
+            let key = f(&x)?;
+            match best {
+                Some((ref best_key, _)) if key >= *best_key => {}
+                _ => best = Some((key, x)),
+            }
+        }
+        Ok(best.map(|(_, x)| x))
+    }
+
+    fn checksum_ok<H, F>(self, mut make_hasher: F) -> Result<u64, E>
+    where
+        T: Hash,
+        H: Hasher,
+        F: FnMut() -> H,
+    {
+        let mut hasher = make_hasher();
+        for res in self {
+            res?.hash(&mut hasher);
+        }
+        Ok(hasher.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_min_ok_empty() {
+        let empty = || Vec::<Result<usize, &str>>::new().into_iter();
+
+        assert_eq!(empty().max_ok(), Ok(None));
+        assert_eq!(empty().min_ok(), Ok(None));
+        assert_eq!(empty().max_ok_by_key(|&i| i % 3), Ok(None));
+        assert_eq!(empty().min_ok_by_key(|&i| i % 3), Ok(None));
+    }
+
+    #[test]
+    fn test_max_min_ok_all_ok() {
+        let values = || vec![Ok(4), Ok(1), Ok(5), Ok(3)].into_iter();
+
+        assert_eq!(values().max_ok(), Ok::<_, &str>(Some(5)));
+        assert_eq!(values().min_ok(), Ok::<_, &str>(Some(1)));
+        assert_eq!(values().max_ok_by_key(|&i| i % 3), Ok::<_, &str>(Some(5)));
+        assert_eq!(values().min_ok_by_key(|&i| i % 3), Ok::<_, &str>(Some(3)));
+    }
+
+    #[test]
+    fn test_max_min_ok_error_in_the_middle() {
+        let values = || vec![Ok(4), Ok(1), Err("boom"), Ok(5), Err("bang")].into_iter();
+
+        assert_eq!(values().max_ok(), Err("boom"));
+        assert_eq!(values().min_ok(), Err("boom"));
+        assert_eq!(values().max_ok_by_key(|&i| i % 3), Err("boom"));
+        assert_eq!(values().min_ok_by_key(|&i| i % 3), Err("boom"));
+
+        let mut iter = values();
+        assert_eq!(iter.by_ref().max_ok(), Err("boom"));
+        assert_eq!(iter.next(), Some(Ok(5)));
+    }
+
+    #[test]
+    fn test_reduce_ok_empty() {
+        let mut empty = Vec::<Result<usize, &str>>::new().into_iter();
+        assert_eq!(empty.reduce_ok(|a, b| a + b), Ok(None));
+    }
+
+    #[test]
+    fn test_reduce_ok_single_element() {
+        let mut single = vec![Ok::<_, &str>(42)].into_iter();
+        assert_eq!(single.reduce_ok(|a, b| a + b), Ok(Some(42)));
+
+        let mut single = vec![Err::<usize, _>("boom")].into_iter();
+        assert_eq!(single.reduce_ok(|a, b| a + b), Err("boom"));
+    }
+
+    #[test]
+    fn test_reduce_ok_error_in_the_middle() {
+        let mut iter = vec![Ok(4), Ok(1), Err("boom"), Ok(5), Ok(3)].into_iter();
+
+        assert_eq!(iter.reduce_ok(|a, b| a + b), Err("boom"));
+        assert_eq!(iter.reduce_ok(|a, b| a + b), Ok(Some(8)));
+    }
+
+    #[test]
+    fn test_try_max_min_by_key_ties() {
+        let values = || {
+            vec![
+                Ok::<_, &str>((1, 'a')),
+                Ok((3, 'b')),
+                Ok((1, 'c')),
+                Ok((3, 'd')),
+            ]
+        };
+        let key = |&(k, _): &(i32, char)| Ok(k);
+
+        assert_eq!(values().into_iter().try_max_by_key(key), Ok(Some((3, 'd'))));
+        assert_eq!(values().into_iter().try_min_by_key(key), Ok(Some((1, 'a'))));
+    }
+
+    #[test]
+    fn test_try_max_min_by_key_failing_key() {
+        let values = || vec![Ok(4), Ok(-1), Ok(5), Err("boom")].into_iter();
+        let key = |&i: &i32| if i < 0 { Err("negative") } else { Ok(i) };
+
+        let mut iter = values();
+        assert_eq!(iter.try_max_by_key(key), Err("negative"));
+        assert_eq!(iter.next(), Some(Ok(5)));
+
+        assert_eq!(values().try_min_by_key(key), Err("negative"));
+    }
+
+    #[test]
+    fn test_try_max_min_by_key_error_before_failing_key() {
+        let values = || vec![Ok(4), Err("boom"), Ok(-1)].into_iter();
+        let key = |&i: &i32| if i < 0 { Err("negative") } else { Ok(i) };
+
+        assert_eq!(values().try_max_by_key(key), Err("boom"));
+        assert_eq!(values().try_min_by_key(key), Err("boom"));
+    }
+
+    #[test]
+    fn test_checksum_ok_equal_streams() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let owned = vec![Ok::<_, ()>("a".to_owned()), Ok("b".to_owned())];
+        let borrowed = vec![Ok::<_, ()>("a"), Ok("b")];
+
+        let checksum = owned.into_iter().checksum_ok(DefaultHasher::new);
+        assert!(checksum.is_ok());
+        assert_eq!(
+            checksum,
+            borrowed.into_iter().checksum_ok(DefaultHasher::new)
+        );
+    }
+
+    #[test]
+    fn test_checksum_ok_aborted_by_error() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let mut iter = vec![Ok(1), Err("a"), Ok(2)].into_iter();
+        assert_eq!(iter.by_ref().checksum_ok(DefaultHasher::new), Err("a"));
+        assert_eq!(iter.next(), Some(Ok(2)));
+    }
+}
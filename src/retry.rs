@@ -0,0 +1,194 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to replace the errors
+/// with the values produced by a retrying function.
+pub trait Retry<O, E>: Sized {
+    /// On every `Err` call the `retry` function up to `attempts` times,
+    /// yielding the first `Ok` value it produces, or the last `Err` if all the attempts fail.
+    ///
+    /// The underlying iterator is advanced exactly once per item,
+    /// the `retry` function only supplies the replacement values.
+    /// The function is not given the failed value, use
+    /// [`retry_ok_with`](Retry::retry_ok_with) if it is needed.
+    ///
+    /// With zero `attempts`, the original errors are yielded as is.
+    ///
+    /// ```
+    /// use resiter::retry::Retry;
+    ///
+    /// let mut calls = 0;
+    /// let fetched: Vec<_> = vec![Ok(1), Err("timeout"), Ok(3)]
+    ///     .into_iter()
+    ///     .retry_ok(3, || {
+    ///         calls += 1;
+    ///         if calls < 2 {
+    ///             Err("timeout again")
+    ///         } else {
+    ///             Ok(2)
+    ///         }
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(fetched, [Ok(1), Ok(2), Ok(3)]);
+    /// ```
+    fn retry_ok<F>(self, attempts: usize, retry: F) -> RetryOk<Self, F>
+    where
+        F: FnMut() -> Result<O, E>;
+
+    /// On every `Err` call the `retry` function up to `attempts` times,
+    /// same as [`retry_ok`](Retry::retry_ok), but giving it the last error.
+    ///
+    /// ```
+    /// use resiter::retry::Retry;
+    ///
+    /// let fetched: Vec<_> = vec![Ok(1), Err(5), Ok(3)]
+    ///     .into_iter()
+    ///     .retry_ok_with(3, |&remaining| {
+    ///         if remaining > 3 {
+    ///             Err(remaining - 1)
+    ///         } else {
+    ///             Ok(remaining * 10)
+    ///         }
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(fetched, [Ok(1), Ok(30), Ok(3)]);
+    /// ```
+    fn retry_ok_with<F>(self, attempts: usize, retry: F) -> RetryOkWith<Self, F>
+    where
+        F: FnMut(&E) -> Result<O, E>;
+}
+
+impl<I, O, E> Retry<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn retry_ok<F>(self, attempts: usize, retry: F) -> RetryOk<Self, F>
+    where
+        F: FnMut() -> Result<O, E>,
+    {
+        RetryOk {
+            iter: self,
+            attempts,
+            retry,
+        }
+    }
+
+    #[inline]
+    fn retry_ok_with<F>(self, attempts: usize, retry: F) -> RetryOkWith<Self, F>
+    where
+        F: FnMut(&E) -> Result<O, E>,
+    {
+        RetryOkWith {
+            iter: self,
+            attempts,
+            retry,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct RetryOk<I, F> {
+    iter: I,
+    attempts: usize,
+    retry: F,
+}
+
+impl<I, O, E, F> Iterator for RetryOk<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut() -> Result<O, E>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut res = self.iter.next()?;
+        for _ in 0..self.attempts {
+            if res.is_ok() {
+                break;
+            }
+            res = (self.retry)();
+        }
+        Some(res)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct RetryOkWith<I, F> {
+    iter: I,
+    attempts: usize,
+    retry: F,
+}
+
+impl<I, O, E, F> Iterator for RetryOkWith<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&E) -> Result<O, E>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut res = self.iter.next()?;
+        for _ in 0..self.attempts {
+            match res {
+                Ok(_) => break,
+                Err(e) => res = (self.retry)(&e),
+            }
+        }
+        Some(res)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_ok_flaky_source() {
+        let mut calls = 0;
+        let fetched: Vec<_> = vec![Err("a"), Ok(10), Err("b"), Err("c")]
+            .into_iter()
+            .retry_ok(2, || {
+                calls += 1;
+                // succeed on every third call only
+                if calls % 3 == 0 {
+                    Ok(calls)
+                } else {
+                    Err("flaky")
+                }
+            })
+            .collect();
+
+        // the first error exhausts the attempts, the second one succeeds on the 3rd call,
+        // and the third one gets the 4th and 5th calls failing
+        assert_eq!(fetched, [Err("flaky"), Ok(10), Ok(3), Err("flaky")]);
+        assert_eq!(calls, 5);
+    }
+
+    #[test]
+    fn test_retry_ok_zero_attempts() {
+        let fetched: Vec<_> = vec![Ok(1), Err("a")]
+            .into_iter()
+            .retry_ok(0, || unreachable!())
+            .collect();
+
+        assert_eq!(fetched, [Ok(1), Err("a")]);
+    }
+}
@@ -0,0 +1,125 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to transform the `Ok` values
+/// holding the internal state.
+pub trait Scan<O, E>: Sized {
+    /// [scan](Iterator::scan) every `Ok` value while leaving `Err` as is (and the state untouched).
+    ///
+    /// When the function returns `None`, the iteration ends for good:
+    /// nothing is yielded after it (not even the errors following it).
+    ///
+    /// ```
+    /// use resiter::scan::Scan;
+    ///
+    /// let running_sums: Vec<_> = vec![Ok(1), Ok(2), Err("a"), Ok(3), Err("b")]
+    ///     .into_iter()
+    ///     .scan_ok(0, |sum, i| {
+    ///         *sum += i;
+    ///         Some(*sum)
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(running_sums, [Ok(1), Ok(3), Err("a"), Ok(6), Err("b")]);
+    /// ```
+    fn scan_ok<St, B, F>(self, initial_state: St, _: F) -> ScanOk<Self, St, F>
+    where
+        F: FnMut(&mut St, O) -> Option<B>;
+}
+
+impl<I, O, E> Scan<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn scan_ok<St, B, F>(self, initial_state: St, f: F) -> ScanOk<Self, St, F>
+    where
+        F: FnMut(&mut St, O) -> Option<B>,
+    {
+        ScanOk {
+            iter: self,
+            state: initial_state,
+            f,
+            done: false,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ScanOk<I, St, F> {
+    iter: I,
+    state: St,
+    f: F,
+    done: bool,
+}
+
+impl<I, O, E, St, B, F> Iterator for ScanOk<I, St, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&mut St, O) -> Option<B>,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.iter.next()? {
+            Ok(x) => {
+                let scanned = (self.f)(&mut self.state, x);
+                if scanned.is_none() {
+                    self.done = true;
+                }
+                scanned.map(Ok)
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_ok_ends_on_none() {
+        let mut scanned = vec![Ok(1), Err("a"), Ok(2), Ok(3), Err("b")]
+            .into_iter()
+            .scan_ok((), |_, i| if i < 3 { Some(i * 10) } else { None });
+
+        assert_eq!(scanned.next(), Some(Ok(10)));
+        assert_eq!(scanned.next(), Some(Err("a")));
+        assert_eq!(scanned.next(), Some(Ok(20)));
+        assert_eq!(scanned.next(), None);
+    }
+
+    #[test]
+    fn test_scan_ok_nothing_after_none() {
+        let mut source = vec![Ok(1), Ok(5), Err("a"), Ok(2), Err("b")].into_iter();
+        let mut scanned = source
+            .by_ref()
+            .scan_ok((), |_, i| if i < 3 { Some(i) } else { None });
+
+        assert_eq!(scanned.next(), Some(Ok(1)));
+        assert_eq!(scanned.next(), None);
+        assert_eq!(scanned.size_hint(), (0, Some(0)));
+        assert_eq!(scanned.next(), None);
+        assert_eq!(scanned.next(), None);
+        // the source is not advanced after the `None`
+        assert_eq!(source.next(), Some(Err("a")));
+    }
+}
@@ -0,0 +1,92 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to skip a number of the `Ok` values.
+pub trait Skip<O, E>: Sized {
+    /// Skip the first `n` `Ok` values, then pass all the remaining items as is.
+    ///
+    /// Unlike [`Iterator::skip`], only the `Ok` values are counted:
+    /// the errors encountered while skipping are not dropped, but forwarded.
+    ///
+    /// ```
+    /// use resiter::skip::Skip;
+    ///
+    /// let rest: Vec<_> = vec![Ok(1), Err("a"), Ok(2), Ok(3), Err("b")]
+    ///     .into_iter()
+    ///     .skip_ok(2)
+    ///     .collect();
+    ///
+    /// assert_eq!(rest, [Err("a"), Ok(3), Err("b")]);
+    /// ```
+    fn skip_ok(self, n: usize) -> SkipOk<Self>;
+}
+
+impl<I, O, E> Skip<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn skip_ok(self, n: usize) -> SkipOk<Self> {
+        SkipOk { iter: self, n }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct SkipOk<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I, O, E> Iterator for SkipOk<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.n > 0 {
+            match self.iter.next()? {
+                Ok(_) => self.n -= 1,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (lo.saturating_sub(self.n), hi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skip_ok_interleaved_errors() {
+        let mut rest = vec![Err("a"), Ok(1), Err("b"), Ok(2), Ok(3), Err("c")]
+            .into_iter()
+            .skip_ok(2);
+
+        assert_eq!(rest.size_hint(), (4, Some(6)));
+        assert_eq!(rest.next(), Some(Err("a")));
+        assert_eq!(rest.next(), Some(Err("b")));
+        assert_eq!(rest.next(), Some(Ok(3)));
+        assert_eq!(rest.size_hint(), (1, Some(1)));
+        assert_eq!(rest.next(), Some(Err("c")));
+        assert_eq!(rest.next(), None);
+    }
+
+    #[test]
+    fn test_skip_ok_not_enough_oks() {
+        let rest: Vec<_> = vec![Ok(1), Err("a")].into_iter().skip_ok(5).collect();
+
+        assert_eq!(rest, [Err("a")]);
+    }
+}
@@ -0,0 +1,95 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to skip the leading items.
+pub trait SkipWhile<O, E>: Sized {
+    /// Skip all the `Err` values until the first `Ok`,
+    /// then pass all the remaining items (including the later errors) as is.
+    ///
+    /// ```
+    /// use resiter::skip_while::SkipWhile;
+    ///
+    /// let rest: Vec<_> = vec![Err("a"), Err("b"), Ok(1), Err("c"), Ok(2)]
+    ///     .into_iter()
+    ///     .skip_while_err()
+    ///     .collect();
+    ///
+    /// assert_eq!(rest, [Ok(1), Err("c"), Ok(2)]);
+    /// ```
+    fn skip_while_err(self) -> SkipWhileErr<Self>;
+}
+
+impl<I, O, E> SkipWhile<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn skip_while_err(self) -> SkipWhileErr<Self> {
+        SkipWhileErr {
+            iter: self,
+            started: false,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct SkipWhileErr<I> {
+    iter: I,
+    started: bool,
+}
+
+impl<I, O, E> Iterator for SkipWhileErr<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.started {
+            return self.iter.next();
+        }
+
+        loop {
+            match self.iter.next() {
+                Some(Err(_)) => {}
+                other => {
+                    self.started = true;
+                    return other;
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.iter.size_hint();
+        if self.started {
+            hint
+        } else {
+            (0, hint.1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skip_while_err_pass_through() {
+        let mut rest = vec![Err("a"), Ok(1), Err("b"), Ok(2)]
+            .into_iter()
+            .skip_while_err();
+
+        assert_eq!(rest.size_hint(), (0, Some(4)));
+        assert_eq!(rest.next(), Some(Ok(1)));
+        assert_eq!(rest.size_hint(), (2, Some(2)));
+        assert_eq!(rest.next(), Some(Err("b")));
+        assert_eq!(rest.next(), Some(Ok(2)));
+        assert_eq!(rest.next(), None);
+    }
+}
@@ -0,0 +1,167 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#[cfg(not(test))]
+use core::cell::RefCell;
+#[cfg(test)]
+use std::cell::RefCell;
+
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to split the `Ok` and `Err` values
+/// into two separate iterators.
+pub trait Split<O, E>: Sized {
+    /// Split the iterator into a pair of lazy iterators over the `Ok` and the `Err` values,
+    /// sharing the same source.
+    ///
+    /// Unlike [`partition_results`](crate::collect::CollectResult::partition_results),
+    /// nothing is collected upfront: when one of the sides pulls the items
+    /// from the source, the values of the other kind are buffered for the other side.
+    ///
+    /// The buffer is not bounded, so if the sides are consumed at very different rates
+    /// (e.g. one of them is only consumed after the other is exhausted), all the values
+    /// of the lagging side are kept in memory. Once one of the sides is dropped,
+    /// the other one stops buffering and just skips the values of the dropped kind.
+    ///
+    /// ```
+    /// use resiter::split::Split;
+    ///
+    /// let (oks, errors) = vec![Ok(1), Err("a"), Ok(2), Err("b")]
+    ///     .into_iter()
+    ///     .split_results();
+    ///
+    /// assert_eq!(oks.collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(errors.collect::<Vec<_>>(), ["a", "b"]);
+    /// ```
+    fn split_results(self) -> (OkSide<Self, O, E>, ErrSide<Self, O, E>);
+}
+
+impl<I, O, E> Split<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    fn split_results(self) -> (OkSide<Self, O, E>, ErrSide<Self, O, E>) {
+        let buffer = Rc::new(RefCell::new(SplitBuffer {
+            iter: self,
+            oks: VecDeque::new(),
+            errors: VecDeque::new(),
+        }));
+        (OkSide(Rc::clone(&buffer)), ErrSide(buffer))
+    }
+}
+
+struct SplitBuffer<I, O, E> {
+    iter: I,
+    oks: VecDeque<O>,
+    errors: VecDeque<E>,
+}
+
+/// The iterator over the `Ok` values returned by [`split_results`](Split::split_results)
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct OkSide<I, O, E>(Rc<RefCell<SplitBuffer<I, O, E>>>);
+
+impl<I, O, E> Iterator for OkSide<I, O, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let shared = Rc::strong_count(&self.0) > 1;
+        let mut buffer = self.0.borrow_mut();
+        if let Some(o) = buffer.oks.pop_front() {
+            return Some(o);
+        }
+
+        loop {
+            match buffer.iter.next()? {
+                Ok(o) => return Some(o),
+                Err(e) => {
+                    if shared {
+                        buffer.errors.push_back(e);
+                    }
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffer = self.0.borrow();
+        let buffered = buffer.oks.len();
+        let hint_sup = buffer.iter.size_hint().1;
+        (buffered, hint_sup.and_then(|hi| hi.checked_add(buffered)))
+    }
+}
+
+/// The iterator over the `Err` values returned by [`split_results`](Split::split_results)
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ErrSide<I, O, E>(Rc<RefCell<SplitBuffer<I, O, E>>>);
+
+impl<I, O, E> Iterator for ErrSide<I, O, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let shared = Rc::strong_count(&self.0) > 1;
+        let mut buffer = self.0.borrow_mut();
+        if let Some(e) = buffer.errors.pop_front() {
+            return Some(e);
+        }
+
+        loop {
+            match buffer.iter.next()? {
+                Ok(o) => {
+                    if shared {
+                        buffer.oks.push_back(o);
+                    }
+                }
+                Err(e) => return Some(e),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffer = self.0.borrow();
+        let buffered = buffer.errors.len();
+        let hint_sup = buffer.iter.size_hint().1;
+        (buffered, hint_sup.and_then(|hi| hi.checked_add(buffered)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_results_interleaved_pulls() {
+        let (mut oks, mut errors) = vec![Ok(1), Ok(2), Err("a"), Ok(3), Err("b"), Err("c")]
+            .into_iter()
+            .split_results();
+
+        assert_eq!(errors.next(), Some("a"));
+        // both of the preceding values are buffered
+        assert_eq!(oks.size_hint(), (2, Some(5)));
+        assert_eq!(oks.next(), Some(1));
+        assert_eq!(oks.next(), Some(2));
+        assert_eq!(oks.next(), Some(3));
+        assert_eq!(errors.size_hint(), (0, Some(2)));
+        assert_eq!(oks.next(), None);
+        assert_eq!(errors.next(), Some("b"));
+        assert_eq!(errors.next(), Some("c"));
+        assert_eq!(errors.next(), None);
+    }
+
+    #[test]
+    fn test_split_results_other_side_dropped() {
+        let (oks, errors) = vec![Ok(1), Err("a"), Ok(2)].into_iter().split_results();
+        drop(errors);
+
+        assert_eq!(oks.collect::<Vec<_>>(), [1, 2]);
+    }
+}
@@ -0,0 +1,576 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Adapters for the [`Stream<Item = Result<O, E>>`](Stream) mirroring the iterator ones.
+
+#[cfg(not(test))]
+use core::fmt;
+#[cfg(not(test))]
+use core::future::Future;
+#[cfg(not(test))]
+use core::pin::Pin;
+#[cfg(not(test))]
+use core::task::{Context, Poll};
+#[cfg(test)]
+use std::fmt;
+#[cfg(test)]
+use std::future::Future;
+#[cfg(test)]
+use std::pin::Pin;
+#[cfg(test)]
+use std::task::{Context, Poll};
+
+#[cfg(feature = "futures-timer")]
+use std::time::Duration;
+
+use futures_core::{ready, Stream};
+#[cfg(feature = "futures-timer")]
+use futures_timer::Delay;
+use pin_project_lite::pin_project;
+
+/// Extension trait for `Stream<Item = Result<O, E>>` to selectively transform Oks and Errors.
+pub trait TryStreamResiterExt<O, E>: Sized {
+    /// Map all `Ok` items while leaving `Err` as is
+    ///
+    /// See also [`Map::map_ok`][crate::map::Map::map_ok].
+    fn map_ok<F, O2>(self, _: F) -> MapOk<Self, F>
+    where
+        F: FnMut(O) -> O2;
+
+    /// Filter `Ok` items while keeping `Err`
+    ///
+    /// See also [`Filter::filter_ok`][crate::filter::Filter::filter_ok].
+    fn filter_ok<F>(self, _: F) -> FilterOk<Self, F>
+    where
+        F: FnMut(&O) -> bool;
+
+    /// Map oks selectively, possibly converting them to errors
+    ///
+    /// See also [`AndThen::and_then_ok`][crate::and_then::AndThen::and_then_ok].
+    fn and_then_ok<F, O2>(self, _: F) -> AndThenOk<Self, F>
+    where
+        F: FnMut(O) -> Result<O2, E>;
+
+    /// Map oks selectively with an asynchronous function, possibly converting them to errors
+    ///
+    /// The futures are run one at a time: the next item is not pulled from the stream
+    /// until the future for the previous `Ok` is complete.
+    /// Both the `Err` items of the stream and the errors of the futures are forwarded as is.
+    fn and_then_ok_async<F, Fut, O2>(self, _: F) -> AndThenOkAsync<Self, F, Fut>
+    where
+        F: FnMut(O) -> Fut,
+        Fut: Future<Output = Result<O2, E>>;
+
+    /// Do something with every `Err` item and leave all items as is
+    ///
+    /// See also [`Inspect::inspect_err`][crate::inspect::Inspect::inspect_err].
+    fn inspect_err<F>(self, _: F) -> InspectErr<Self, F>
+    where
+        F: FnMut(&E);
+
+    /// Yield an `Err` converted from the [`TimeoutError`] whenever no item arrives
+    /// within the `dur`, passing all the items arriving in time as is.
+    ///
+    /// The timer is started when the stream starts waiting for the next item
+    /// and is dropped as soon as that item arrives.
+    /// If the timer fires first, the error is yielded and the stream is not ended:
+    /// the next poll starts waiting again, with a new timer.
+    ///
+    /// The timer is a [`futures_timer::Delay`], see the
+    /// [`ok_timeout_with`](TryStreamResiterExt::ok_timeout_with) for any other one.
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt as _};
+    /// use resiter::stream::{TimeoutError, TryStreamResiterExt};
+    /// use std::time::Duration;
+    ///
+    /// let never = stream::pending::<Result<i32, TimeoutError>>();
+    /// let mut timed = never.ok_timeout(Duration::from_millis(10));
+    /// assert_eq!(block_on(timed.next()), Some(Err(TimeoutError)));
+    /// ```
+    #[cfg(feature = "futures-timer")]
+    fn ok_timeout(self, dur: Duration) -> OkTimeout<Self>
+    where
+        E: From<TimeoutError>;
+
+    /// Same as the [`ok_timeout`](TryStreamResiterExt::ok_timeout),
+    /// but with the timer created by the specified function.
+    ///
+    /// The crate does not depend on any async runtime, so this is the way
+    /// to use the runtime's own timer, e.g. `|| tokio::time::sleep(dur)`.
+    /// The function is called every time the stream starts waiting for the next item.
+    fn ok_timeout_with<F, T>(self, _: F) -> OkTimeoutWith<Self, F, T>
+    where
+        E: From<TimeoutError>,
+        F: FnMut() -> T,
+        T: Future<Output = ()>;
+}
+
+/// The error injected by the [`ok_timeout`](TryStreamResiterExt::ok_timeout)
+/// and the [`ok_timeout_with`](TryStreamResiterExt::ok_timeout_with)
+/// when no item arrives in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeoutError;
+
+impl fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("no item arrived in time")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TimeoutError {}
+
+impl<S, O, E> TryStreamResiterExt<O, E> for S
+where
+    S: Stream<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn map_ok<F, O2>(self, f: F) -> MapOk<Self, F>
+    where
+        F: FnMut(O) -> O2,
+    {
+        MapOk { stream: self, f }
+    }
+
+    #[inline]
+    fn filter_ok<F>(self, f: F) -> FilterOk<Self, F>
+    where
+        F: FnMut(&O) -> bool,
+    {
+        FilterOk { stream: self, f }
+    }
+
+    #[inline]
+    fn and_then_ok<F, O2>(self, f: F) -> AndThenOk<Self, F>
+    where
+        F: FnMut(O) -> Result<O2, E>,
+    {
+        AndThenOk { stream: self, f }
+    }
+
+    #[inline]
+    fn and_then_ok_async<F, Fut, O2>(self, f: F) -> AndThenOkAsync<Self, F, Fut>
+    where
+        F: FnMut(O) -> Fut,
+        Fut: Future<Output = Result<O2, E>>,
+    {
+        AndThenOkAsync {
+            stream: self,
+            f,
+            pending: None,
+        }
+    }
+
+    #[inline]
+    fn inspect_err<F>(self, f: F) -> InspectErr<Self, F>
+    where
+        F: FnMut(&E),
+    {
+        InspectErr { stream: self, f }
+    }
+
+    #[inline]
+    #[cfg(feature = "futures-timer")]
+    fn ok_timeout(self, dur: Duration) -> OkTimeout<Self>
+    where
+        E: From<TimeoutError>,
+    {
+        OkTimeout {
+            stream: self,
+            dur,
+            pending: None,
+        }
+    }
+
+    #[inline]
+    fn ok_timeout_with<F, T>(self, timer: F) -> OkTimeoutWith<Self, F, T>
+    where
+        E: From<TimeoutError>,
+        F: FnMut() -> T,
+        T: Future<Output = ()>,
+    {
+        OkTimeoutWith {
+            stream: self,
+            timer,
+            pending: None,
+        }
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct MapOk<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, O, E, F, O2> Stream for MapOk<S, F>
+where
+    S: Stream<Item = Result<O, E>>,
+    F: FnMut(O) -> O2,
+{
+    type Item = Result<O2, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let f = this.f;
+        this.stream.poll_next(cx).map(|item| item.map(|r| r.map(f)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct FilterOk<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, O, E, F> Stream for FilterOk<S, F>
+where
+    S: Stream<Item = Result<O, E>>,
+    F: FnMut(&O) -> bool,
+{
+    type Item = Result<O, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(x))) => {
+                    if (this.f)(&x) {
+                        return Poll::Ready(Some(Ok(x)));
+                    }
+                }
+                other => return other,
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.stream.size_hint().1;
+        (0, hint_sup)
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct AndThenOk<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, O, E, O2, F> Stream for AndThenOk<S, F>
+where
+    S: Stream<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<O2, E>,
+{
+    type Item = Result<O2, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let f = this.f;
+        this.stream
+            .poll_next(cx)
+            .map(|item| item.map(|r| r.and_then(f)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct AndThenOkAsync<S, F, Fut> {
+        #[pin]
+        stream: S,
+        f: F,
+        #[pin]
+        pending: Option<Fut>,
+    }
+}
+
+impl<S, O, E, F, Fut, O2> Stream for AndThenOkAsync<S, F, Fut>
+where
+    S: Stream<Item = Result<O, E>>,
+    F: FnMut(O) -> Fut,
+    Fut: Future<Output = Result<O2, E>>,
+{
+    type Item = Result<O2, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            if let Some(fut) = this.pending.as_mut().as_pin_mut() {
+                let res = ready!(fut.poll(cx));
+                this.pending.set(None);
+                return Poll::Ready(Some(res));
+            }
+
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(Ok(x)) => this.pending.set(Some((this.f)(x))),
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.pending.is_some());
+        let (lo, hi) = self.stream.size_hint();
+        (
+            lo.saturating_add(pending),
+            hi.and_then(|hi| hi.checked_add(pending)),
+        )
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct InspectErr<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, O, E, F> Stream for InspectErr<S, F>
+where
+    S: Stream<Item = Result<O, E>>,
+    F: FnMut(&E),
+{
+    type Item = Result<O, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let f = this.f;
+        this.stream.poll_next(cx).map(|item| {
+            item.map(|r| {
+                if let Err(ref e) = r {
+                    f(e);
+                }
+                r
+            })
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+/// Wait for the next item of the stream, or for the timer (created if there is none yet),
+/// whichever comes first.
+fn poll_timeout<S, O, E, T, M>(
+    stream: Pin<&mut S>,
+    mut pending: Pin<&mut Option<T>>,
+    make_timer: M,
+    cx: &mut Context<'_>,
+) -> Poll<Option<Result<O, E>>>
+where
+    S: Stream<Item = Result<O, E>>,
+    E: From<TimeoutError>,
+    T: Future<Output = ()>,
+    M: FnOnce() -> T,
+{
+    if let Poll::Ready(item) = stream.poll_next(cx) {
+        pending.set(None);
+        return Poll::Ready(item);
+    }
+
+    if pending.is_none() {
+        pending.set(Some(make_timer()));
+    }
+    if let Some(timer) = pending.as_mut().as_pin_mut() {
+        ready!(timer.poll(cx));
+    }
+    pending.set(None);
+    Poll::Ready(Some(Err(TimeoutError.into())))
+}
+
+#[cfg(feature = "futures-timer")]
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct OkTimeout<S> {
+        #[pin]
+        stream: S,
+        dur: Duration,
+        #[pin]
+        pending: Option<Delay>,
+    }
+}
+
+#[cfg(feature = "futures-timer")]
+impl<S, O, E> Stream for OkTimeout<S>
+where
+    S: Stream<Item = Result<O, E>>,
+    E: From<TimeoutError>,
+{
+    type Item = Result<O, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let dur = *this.dur;
+        poll_timeout(this.stream, this.pending, || Delay::new(dur), cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // any number of timeouts could be injected
+        (self.stream.size_hint().0, None)
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct OkTimeoutWith<S, F, T> {
+        #[pin]
+        stream: S,
+        timer: F,
+        #[pin]
+        pending: Option<T>,
+    }
+}
+
+impl<S, O, E, F, T> Stream for OkTimeoutWith<S, F, T>
+where
+    S: Stream<Item = Result<O, E>>,
+    E: From<TimeoutError>,
+    F: FnMut() -> T,
+    T: Future<Output = ()>,
+{
+    type Item = Result<O, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        poll_timeout(this.stream, this.pending, this.timer, cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // any number of timeouts could be injected
+        (self.stream.size_hint().0, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::executor::block_on;
+    use futures::future;
+    use futures::stream::{self, StreamExt as _};
+
+    #[test]
+    fn test_stream_adapters() {
+        let mut errors = Vec::new();
+        let source = stream::iter(vec![Ok(1), Err("a"), Ok(2), Ok(3), Ok(4), Err("b")]);
+        let res: Vec<_> = block_on(
+            source
+                .map_ok(|i| i * 10)
+                .filter_ok(|i| *i != 20)
+                .and_then_ok(|i| if i < 40 { Ok(i) } else { Err("too big") })
+                .inspect_err(|e| errors.push(*e))
+                .collect(),
+        );
+
+        assert_eq!(res, [Ok(10), Err("a"), Ok(30), Err("too big"), Err("b")]);
+        assert_eq!(errors, ["a", "too big", "b"]);
+    }
+
+    #[test]
+    fn test_stream_filter_ok_hint() {
+        let hint = stream::iter(vec![Ok(1), Err("a"), Ok(2)])
+            .filter_ok(|i| i % 2 == 0)
+            .size_hint();
+
+        assert_eq!(hint, (0, Some(3)));
+    }
+
+    #[test]
+    fn test_stream_and_then_ok_async() {
+        // the future is not ready on the first poll, to check the in-flight one is kept
+        fn lookup(i: i32) -> impl Future<Output = Result<i32, &'static str>> {
+            let mut polled = false;
+            future::poll_fn(move |cx| {
+                if !polled {
+                    polled = true;
+                    cx.waker().wake_by_ref();
+                    return Poll::Pending;
+                }
+                Poll::Ready(if i < 3 { Ok(i * 10) } else { Err("not found") })
+            })
+        }
+
+        let source = stream::iter(vec![Ok(1), Err("a"), Ok(2), Ok(3)]);
+        let res: Vec<_> = block_on(source.and_then_ok_async(lookup).collect());
+
+        assert_eq!(res, [Ok(10), Err("a"), Ok(20), Err("not found")]);
+    }
+
+    #[test]
+    fn test_stream_ok_timeout_delayed_item() {
+        // the timer fires on the second poll
+        fn timer() -> impl Future<Output = ()> {
+            let mut polls = 0;
+            future::poll_fn(move |cx| {
+                polls += 1;
+                if polls < 2 {
+                    cx.waker().wake_by_ref();
+                    return Poll::Pending;
+                }
+                Poll::Ready(())
+            })
+        }
+
+        // the second item only arrives on the fifth poll, the stream ends on the sixth one
+        let mut polls = 0;
+        let source = stream::poll_fn(move |cx| {
+            polls += 1;
+            match polls {
+                1 => Poll::Ready(Some(Ok(1))),
+                2..=4 => {
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+                5 => Poll::Ready(Some(Ok(2))),
+                _ => Poll::Ready(None),
+            }
+        });
+        let res: Vec<Result<i32, TimeoutError>> = block_on(source.ok_timeout_with(timer).collect());
+
+        assert_eq!(res, [Ok(1), Err(TimeoutError), Ok(2)]);
+    }
+
+    #[test]
+    #[cfg(feature = "futures-timer")]
+    fn test_stream_ok_timeout_items_in_time() {
+        use std::time::Duration;
+
+        let source = stream::iter(vec![Ok(1), Err(TimeoutError), Ok(2)]);
+        let res: Vec<_> = block_on(source.ok_timeout(Duration::from_secs(60)).collect());
+
+        // the only error is the one from the source
+        assert_eq!(res, [Ok(1), Err(TimeoutError), Ok(2)]);
+    }
+}
@@ -0,0 +1,101 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to take a number of the `Ok` values.
+pub trait Take<O, E>: Sized {
+    /// Pass the items as is until `n` `Ok` values are yielded, then stop.
+    ///
+    /// Unlike [`Iterator::take`], only the `Ok` values are counted:
+    /// all the errors before the `n`-th `Ok` are yielded as well.
+    /// The iteration ends right after the `n`-th `Ok`,
+    /// so the errors following it are never pulled from the underlying iterator.
+    ///
+    /// ```
+    /// use resiter::take::Take;
+    ///
+    /// let first: Vec<_> = vec![Ok(1), Err("a"), Ok(2), Err("b"), Ok(3)]
+    ///     .into_iter()
+    ///     .take_ok(2)
+    ///     .collect();
+    ///
+    /// assert_eq!(first, [Ok(1), Err("a"), Ok(2)]);
+    /// ```
+    fn take_ok(self, n: usize) -> TakeOk<Self>;
+}
+
+impl<I, O, E> Take<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn take_ok(self, n: usize) -> TakeOk<Self> {
+        TakeOk { iter: self, n }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct TakeOk<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I, O, E> Iterator for TakeOk<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.n == 0 {
+            return None;
+        }
+
+        let item = self.iter.next();
+        if let Some(Ok(_)) = item {
+            self.n -= 1;
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.n == 0 {
+            (0, Some(0))
+        } else {
+            let (lo, hi) = self.iter.size_hint();
+            (lo.min(self.n), hi)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_ok_interleaved_errors() {
+        let mut iter = vec![Err("a"), Ok(1), Err("b"), Ok(2), Err("c"), Ok(3)].into_iter();
+        let mut first = iter.by_ref().take_ok(2);
+
+        assert_eq!(first.size_hint(), (2, Some(6)));
+        assert_eq!(first.next(), Some(Err("a")));
+        assert_eq!(first.next(), Some(Ok(1)));
+        assert_eq!(first.next(), Some(Err("b")));
+        assert_eq!(first.next(), Some(Ok(2)));
+        assert_eq!(first.size_hint(), (0, Some(0)));
+        assert_eq!(first.next(), None);
+        assert_eq!(iter.next(), Some(Err("c")));
+    }
+
+    #[test]
+    fn test_take_ok_zero() {
+        let mut iter = vec![Err::<(), _>("a")].into_iter();
+
+        assert_eq!(iter.by_ref().take_ok(0).next(), None);
+        assert_eq!(iter.next(), Some(Err("a")));
+    }
+}
@@ -0,0 +1,227 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to take the `Ok` values
+/// while they satisfy a predicate or could be mapped.
+pub trait TakeWhile<O, E>: Sized {
+    /// [take_while](Iterator::take_while) on every `Ok` value, also stopping after the first `Err`.
+    ///
+    /// The iteration ends for one of two reasons:
+    /// - the predicate fails on an `Ok` value: that value is dropped
+    ///   and nothing is yielded after it (not even the error following it);
+    /// - an `Err` appears: it is yielded as is and nothing is yielded after it.
+    ///
+    /// ```
+    /// use resiter::take_while::TakeWhile;
+    /// use std::str::FromStr;
+    ///
+    /// let taken: Vec<_> = ["1", "2", "3", "a", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|_| txt))
+    ///     .take_while_ok(|&i| i < 3)
+    ///     .collect();
+    /// assert_eq!(taken, [Ok(1), Ok(2)]);
+    ///
+    /// let taken: Vec<_> = ["1", "2", "a", "3", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|_| txt))
+    ///     .take_while_ok(|&i| i < 3)
+    ///     .collect();
+    /// assert_eq!(taken, [Ok(1), Ok(2), Err(&"a")]);
+    /// ```
+    fn take_while_ok<P>(self, _: P) -> TakeWhileOk<Self, P>
+    where
+        P: FnMut(&O) -> bool;
+
+    /// [map_while](Iterator::map_while) on every `Ok` value, also stopping after the first `Err`.
+    ///
+    /// The iteration ends for one of two reasons:
+    /// - the mapping returns `None` for an `Ok` value: nothing is yielded for it
+    ///   and nothing is yielded after it (not even the error following it);
+    /// - an `Err` appears: it is yielded as is and nothing is yielded after it.
+    ///
+    /// ```
+    /// use resiter::take_while::TakeWhile;
+    ///
+    /// let parsed: Vec<_> = vec![Ok("1"), Ok("2"), Ok("three"), Ok("4"), Err("eof")]
+    ///     .into_iter()
+    ///     .map_while_ok(|txt| txt.parse::<u32>().ok())
+    ///     .collect();
+    /// assert_eq!(parsed, [Ok(1), Ok(2)]);
+    ///
+    /// let parsed: Vec<_> = vec![Ok("1"), Ok("2"), Err("eof"), Ok("4")]
+    ///     .into_iter()
+    ///     .map_while_ok(|txt| txt.parse::<u32>().ok())
+    ///     .collect();
+    /// assert_eq!(parsed, [Ok(1), Ok(2), Err("eof")]);
+    /// ```
+    fn map_while_ok<U, F>(self, _: F) -> MapWhileOk<Self, F>
+    where
+        F: FnMut(O) -> Option<U>;
+}
+
+impl<I, O, E> TakeWhile<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn take_while_ok<P>(self, pred: P) -> TakeWhileOk<Self, P>
+    where
+        P: FnMut(&O) -> bool,
+    {
+        TakeWhileOk {
+            iter: self,
+            pred,
+            done: false,
+        }
+    }
+
+    #[inline]
+    fn map_while_ok<U, F>(self, f: F) -> MapWhileOk<Self, F>
+    where
+        F: FnMut(O) -> Option<U>,
+    {
+        MapWhileOk {
+            iter: self,
+            f,
+            done: false,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct TakeWhileOk<I, P> {
+    iter: I,
+    pred: P,
+    done: bool,
+}
+
+impl<I, O, E, P> Iterator for TakeWhileOk<I, P>
+where
+    I: Iterator<Item = Result<O, E>>,
+    P: FnMut(&O) -> bool,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.iter.next() {
+            Some(Ok(x)) => {
+                if (self.pred)(&x) {
+                    Some(Ok(x))
+                } else {
+                    self.done = true;
+                    None
+                }
+            }
+            Some(Err(e)) => {
+                self.done = true;
+                Some(Err(e))
+            }
+            None => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MapWhileOk<I, F> {
+    iter: I,
+    f: F,
+    done: bool,
+}
+
+impl<I, O, E, U, F> Iterator for MapWhileOk<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Option<U>,
+{
+    type Item = Result<U, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.iter.next() {
+            Some(Ok(x)) => {
+                let mapped = (self.f)(x);
+                if mapped.is_none() {
+                    self.done = true;
+                }
+                mapped.map(Ok)
+            }
+            Some(Err(e)) => {
+                self.done = true;
+                Some(Err(e))
+            }
+            None => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_while_ok_stops_on_predicate() {
+        let mut taken = vec![Ok(1), Ok(5), Err("a"), Ok(2)]
+            .into_iter()
+            .take_while_ok(|&i| i < 3);
+
+        assert_eq!(taken.next(), Some(Ok(1)));
+        assert_eq!(taken.next(), None);
+        assert_eq!(taken.next(), None);
+        assert_eq!(taken.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_take_while_ok_stops_on_error() {
+        let mut taken = vec![Ok(1), Err("a"), Ok(2), Err("b")]
+            .into_iter()
+            .take_while_ok(|&i| i < 3);
+
+        assert_eq!(taken.next(), Some(Ok(1)));
+        assert_eq!(taken.next(), Some(Err("a")));
+        assert_eq!(taken.next(), None);
+        assert_eq!(taken.next(), None);
+    }
+
+    #[test]
+    fn test_map_while_ok_stops_on_none() {
+        let mut iter = vec![Ok::<u32, &str>(1), Ok(2), Ok(0), Ok(4)].into_iter();
+        let mapped: Vec<_> = iter
+            .by_ref()
+            .map_while_ok(|i| 12u32.checked_div(i))
+            .collect();
+
+        assert_eq!(mapped, [Ok(12), Ok(6)]);
+        assert_eq!(iter.next(), Some(Ok(4)));
+    }
+}
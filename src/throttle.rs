@@ -0,0 +1,125 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to limit the number of the errors.
+pub trait Throttle<O, E>: Sized {
+    /// Yield every `Ok` value, but only yield an `Err` if at least `every` `Ok` values
+    /// have passed since the last yielded error. The first error is always yielded.
+    ///
+    /// The counter of the `Ok` values is reset by every *yielded* error only:
+    /// the suppressed errors are silently dropped and do not affect it.
+    /// With `every` equal to 0, nothing is suppressed.
+    ///
+    /// ```
+    /// use resiter::throttle::Throttle;
+    ///
+    /// let throttled: Vec<_> = vec![Err("a"), Ok(1), Err("b"), Ok(2), Ok(3), Err("c")]
+    ///     .into_iter()
+    ///     .throttle_errors(2)
+    ///     .collect();
+    ///
+    /// assert_eq!(throttled, [Err("a"), Ok(1), Ok(2), Ok(3), Err("c")]);
+    /// ```
+    fn throttle_errors(self, every: usize) -> ThrottleErrors<Self>;
+}
+
+impl<I, O, E> Throttle<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn throttle_errors(self, every: usize) -> ThrottleErrors<Self> {
+        ThrottleErrors {
+            iter: self,
+            every,
+            oks_since_err: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ThrottleErrors<I> {
+    iter: I,
+    every: usize,
+    /// The number of `Ok` values since the last yielded error, if any.
+    oks_since_err: Option<usize>,
+}
+
+impl<I, O, E> Iterator for ThrottleErrors<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(x) => {
+                    if let Some(ref mut count) = self.oks_since_err {
+                        *count = count.saturating_add(1);
+                    }
+                    return Some(Ok(x));
+                }
+                Err(e) => {
+                    let allowed = match self.oks_since_err {
+                        Some(count) => count >= self.every,
+                        None => true,
+                    };
+                    if allowed {
+                        self.oks_since_err = Some(0);
+                        return Some(Err(e));
+                    }
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_throttle_errors_close_errors_suppressed() {
+        let throttled: Vec<_> = vec![
+            Ok(1),
+            Err("a"),
+            Ok(2),
+            Err("b"),
+            Err("c"),
+            Ok(3),
+            Ok(4),
+            Ok(5),
+            Err("d"),
+            Err("e"),
+        ]
+        .into_iter()
+        .throttle_errors(3)
+        .collect();
+
+        assert_eq!(
+            throttled,
+            [Ok(1), Err("a"), Ok(2), Ok(3), Ok(4), Ok(5), Err("d")]
+        );
+    }
+
+    #[test]
+    fn test_throttle_errors_zero() {
+        let throttled: Vec<_> = vec![Err::<(), _>("a"), Err("b")]
+            .into_iter()
+            .throttle_errors(0)
+            .collect();
+
+        assert_eq!(throttled, [Err("a"), Err("b")]);
+    }
+}
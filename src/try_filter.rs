@@ -71,12 +71,14 @@ where
     }
 }
 
+#[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct TryFilterOk<I, F> {
     iter: I,
     f: F,
 }
 
+#[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct TryFilterErr<I, F> {
     iter: I,
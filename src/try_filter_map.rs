@@ -123,6 +123,7 @@ where
     }
 }
 
+#[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct TryFilterMapOk<I, F> {
     iter: I,
@@ -155,6 +156,7 @@ where
     }
 }
 
+#[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct TryFilterMapErr<I, F> {
     iter: I,
@@ -121,6 +121,7 @@ where
     }
 }
 
+#[derive(Clone)]
 pub struct TryMapOk<I, F> {
     iter: I,
     f: F,
@@ -147,6 +148,7 @@ where
     }
 }
 
+#[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct TryMapErr<I, F> {
     iter: I,
@@ -1,3 +1,9 @@
+#[cfg(not(test))]
+use core::iter::FromIterator;
+#[cfg(test)]
+use std::iter::FromIterator;
+
+use crate::collect::TryCollect as _;
 use crate::errors::GetErrors as _;
 
 /// Extension trait for `Iterator<Item = Result<(), E>>` (or other `T` _isomorphic_ to unit `()`)
@@ -47,12 +53,41 @@ pub trait FoldUnit<T, E>: Iterator<Item = Result<T, E>> {
     /// ```
     ///
     /// See also for somewhat similar functionality: [`Errors`][crate::errors::Errors];
-    fn fail_fast(&mut self) -> Result<T, E>;
+    fn fail_fast(&mut self) -> Result<T, E>
+    where
+        T: From<()>,
+    {
+        self.fail_fast_with(|| ().into())
+    }
+
+    /// Stop on the first [`Err`] output by skipping all the consequent items,
+    /// same as [`fail_fast`](FoldUnit::fail_fast).
+    ///
+    /// If there are no errors, the `Ok` value is built with the provided function,
+    /// so the `T` does not have to be constructed `From<()>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use resiter::FoldUnit;
+    ///
+    /// let values: Vec<Result<String, _>> = vec![Ok("a".into()), Err("error1"), Err("error2")];
+    /// assert_eq!(values.into_iter().fail_fast_with(|| "done".into()), Err("error1"));
+    ///
+    /// let values: Vec<Result<String, &str>> = vec![Ok("a".into()), Ok("b".into())];
+    /// assert_eq!(
+    ///     values.into_iter().fail_fast_with(|| "done".into()),
+    ///     Ok("done".to_owned())
+    /// );
+    /// ```
+    fn fail_fast_with<F>(&mut self, make_ok: F) -> Result<T, E>
+    where
+        F: FnOnce() -> T;
 
     /// Process the iterator till the end, ignoring all but the last [`Err`].
     ///
     /// This is mostly useful for the stateful calculations
-    /// to ensure independent processing of *every** individual [`Self::Item`]
+    /// to ensure independent processing of *every** individual [`Item`](Iterator::Item)
     /// is done even if some of them are erroneous.
     ///
     /// # Examples
@@ -96,19 +131,188 @@ pub trait FoldUnit<T, E>: Iterator<Item = Result<T, E>> {
     /// See also for somewhat similar functionality:
     /// - [`WhileOk`][crate::while_ok::WhileOk];
     /// - [`OnOkDo`][crate::onok::OnOkDo];
-    fn last_err(&mut self) -> Result<T, E>;
+    fn last_err(&mut self) -> Result<T, E>
+    where
+        T: From<()>,
+    {
+        self.last_err_with(|| ().into())
+    }
+
+    /// Process the iterator till the end, ignoring all but the last [`Err`],
+    /// same as [`last_err`](FoldUnit::last_err).
+    ///
+    /// If there are no errors, the `Ok` value is built with the provided function,
+    /// so the `T` does not have to be constructed `From<()>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use resiter::FoldUnit;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Status {
+    ///     Processed,
+    ///     Skipped,
+    /// }
+    ///
+    /// let values = vec![Ok(Status::Skipped), Err("error1"), Ok(Status::Processed), Err("error2")];
+    /// assert_eq!(values.into_iter().last_err_with(|| Status::Processed), Err("error2"));
+    ///
+    /// let values: Vec<Result<_, &str>> = vec![Ok(Status::Skipped), Ok(Status::Processed)];
+    /// assert_eq!(values.into_iter().last_err_with(|| Status::Processed), Ok(Status::Processed));
+    /// ```
+    fn last_err_with<F>(&mut self, make_ok: F) -> Result<T, E>
+    where
+        F: FnOnce() -> T;
+
+    /// Process the iterator till the end, ignoring all but the last [`Err`],
+    /// same as [`last_err`](FoldUnit::last_err), but also count all the errors seen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use resiter::FoldUnit;
+    ///
+    /// let values = vec![Ok(()), Err("error1"), Ok(()), Err("error2"), Ok(())];
+    /// let (errors, res) = values.into_iter().last_err_counted();
+    /// assert_eq!(errors, 2);
+    /// assert_eq!(res, Err("error2"));
+    /// ```
+    fn last_err_counted(&mut self) -> (usize, Result<T, E>)
+    where
+        T: From<()>;
+
+    /// Consume the iterator stopping on the first [`Err`],
+    /// same as [`fail_fast`](FoldUnit::fail_fast) but taking the iterator by value.
+    ///
+    /// Use it for the one-shot processing when the iterator is not needed afterwards,
+    /// and the [`fail_fast`](FoldUnit::fail_fast) to resume the processing after an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use resiter::FoldUnit;
+    ///
+    /// fn process(values: Vec<Result<(), &'static str>>) -> Result<(), &'static str> {
+    ///     // no need to bind the iterator to a `let mut`
+    ///     values.into_iter().try_run()?;
+    ///     Ok(())
+    /// }
+    ///
+    /// assert_eq!(process(vec![Ok(()), Err("error1"), Err("error2")]), Err("error1"));
+    ///
+    /// // while the `&mut` version allows to continue after the error
+    /// let mut iter = vec![Ok(()), Err("error1"), Ok(()), Err("error2")].into_iter();
+    /// assert_eq!(iter.fail_fast(), Err("error1"));
+    /// assert_eq!(iter.fail_fast(), Err("error2"));
+    /// assert_eq!(iter.fail_fast(), Ok(()));
+    /// ```
+    fn try_run(mut self) -> Result<T, E>
+    where
+        Self: Sized,
+        T: From<()>,
+    {
+        self.fail_fast()
+    }
+
+    /// Consume the whole iterator, ignoring all but the last [`Err`],
+    /// same as [`last_err`](FoldUnit::last_err) but taking the iterator by value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use resiter::FoldUnit;
+    ///
+    /// let values = vec![Ok(()), Err("error1"), Ok(()), Err("error2"), Ok(())];
+    /// assert_eq!(values.into_iter().run_last_err(), Err("error2"));
+    ///
+    /// // the same with the `&mut` version requires a binding
+    /// let mut iter = vec![Ok(()), Err("error1"), Ok(()), Err("error2"), Ok(())].into_iter();
+    /// assert_eq!(iter.last_err(), Err("error2"));
+    /// ```
+    fn run_last_err(mut self) -> Result<T, E>
+    where
+        Self: Sized,
+        T: From<()>,
+    {
+        self.last_err()
+    }
+
+    /// Collect all the `Ok` values into any [`FromIterator`] container,
+    /// stopping on the first [`Err`] and returning it instead.
+    ///
+    /// Unlike the [`fail_fast`](FoldUnit::fail_fast), the `Ok` values are kept
+    /// and so there is no `From<()>` requirement.
+    /// This is the same as [`TryCollect::collect_ok_or_first_err`][crate::collect::TryCollect::collect_ok_or_first_err].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use resiter::FoldUnit;
+    ///
+    /// let values = vec![Ok(1), Ok(2), Ok(3)];
+    /// assert_eq!(values.into_iter().collect_oks_fail_fast::<Vec<_>>(), Ok::<_, &str>(vec![1, 2, 3]));
+    ///
+    /// let values = vec![Ok('a'), Err("error1"), Ok('b'), Err("error2")];
+    /// assert_eq!(values.into_iter().collect_oks_fail_fast::<String>(), Err("error1"));
+    ///
+    /// let values = vec![Ok("res"), Ok("iter")];
+    /// assert_eq!(values.into_iter().collect_oks_fail_fast::<String>(), Ok::<_, ()>("resiter".to_owned()));
+    /// ```
+    fn collect_oks_fail_fast<C>(self) -> Result<C, E>
+    where
+        Self: Sized,
+        C: FromIterator<T>,
+    {
+        self.collect_ok_or_first_err()
+    }
 }
 
 impl<I, T, E> FoldUnit<T, E> for I
 where
     I: Iterator<Item = Result<T, E>>,
-    T: From<()>,
 {
-    fn fail_fast(&mut self) -> Result<T, E> {
-        self.errors().next().map_or_else(|| Ok(().into()), Err)
+    fn fail_fast_with<F>(&mut self, make_ok: F) -> Result<T, E>
+    where
+        F: FnOnce() -> T,
+    {
+        self.errors().next().map_or_else(|| Ok(make_ok()), Err)
     }
 
-    fn last_err(&mut self) -> Result<T, E> {
-        self.errors().last().map_or_else(|| Ok(().into()), Err)
+    fn last_err_with<F>(&mut self, make_ok: F) -> Result<T, E>
+    where
+        F: FnOnce() -> T,
+    {
+        self.errors().last().map_or_else(|| Ok(make_ok()), Err)
+    }
+
+    fn last_err_counted(&mut self) -> (usize, Result<T, E>)
+    where
+        T: From<()>,
+    {
+        let (count, last) = self
+            .errors()
+            .fold((0, None), |(count, _), e| (count + 1, Some(e)));
+        (count, last.map_or_else(|| Ok(().into()), Err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_last_err_counted() {
+        let mut iter = vec![Ok(()), Err("a"), Ok(()), Err("b"), Ok(())].into_iter();
+
+        assert_eq!(iter.last_err_counted(), (2, Err("b")));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_last_err_counted_no_errors() {
+        let mut iter = vec![Ok::<_, &str>(()), Ok(())].into_iter();
+
+        assert_eq!(iter.last_err_counted(), (0, Ok(())));
     }
 }
@@ -0,0 +1,102 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to iter until a success is encountered.
+///
+/// This is the dual of the [`WhileOk`][crate::while_ok::WhileOk].
+pub trait UntilOk<O, E>: Sized {
+    /// Yield all the `Err` values until the first `Ok`, then yield that `Ok` and stop.
+    ///
+    /// The iteration ends right after the first `Ok`:
+    /// the items following it are never pulled from the underlying iterator.
+    ///
+    /// ```
+    /// use resiter::until_ok::UntilOk;
+    ///
+    /// let attempts: Vec<_> = vec![Err(1), Err(2), Ok("done"), Err(3)]
+    ///     .into_iter()
+    ///     .until_ok()
+    ///     .collect();
+    ///
+    /// assert_eq!(attempts, [Err(1), Err(2), Ok("done")]);
+    /// ```
+    fn until_ok(self) -> UntilErr<Self>;
+}
+
+impl<I, O, E> UntilOk<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn until_ok(self) -> UntilErr<Self> {
+        UntilErr {
+            iter: self,
+            done: false,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct UntilErr<I> {
+    iter: I,
+    done: bool,
+}
+
+impl<I, O, E> Iterator for UntilErr<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let item = self.iter.next();
+        if let Some(Ok(_)) = item {
+            self.done = true;
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (lo, hi) = self.iter.size_hint();
+            (lo.min(1), hi)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_until_ok_stops_after_ok() {
+        let mut iter = vec![Err(1), Ok("a"), Ok("b")].into_iter();
+        let mut until = iter.by_ref().until_ok();
+
+        assert_eq!(until.next(), Some(Err(1)));
+        assert_eq!(until.next(), Some(Ok("a")));
+        assert_eq!(until.next(), None);
+        assert_eq!(iter.next(), Some(Ok("b")));
+    }
+
+    #[test]
+    fn test_until_ok_all_errors() {
+        let errors: Vec<_> = vec![Err::<(), _>(1), Err(2)]
+            .into_iter()
+            .until_ok()
+            .collect();
+
+        assert_eq!(errors, [Err(1), Err(2)]);
+    }
+}
@@ -17,6 +17,16 @@ where
     I: Iterator<Item = Result<O, E>>,
     F: FnMut(E) -> Option<O>;
 
+impl<I, O, E, F> Clone for UnwrapWith<I, O, E, F>
+where
+    I: Iterator<Item = Result<O, E>> + Clone,
+    F: FnMut(E) -> Option<O> + Clone,
+{
+    fn clone(&self) -> Self {
+        UnwrapWith(self.0.clone(), self.1.clone())
+    }
+}
+
 impl<I, O, E, F> Iterator for UnwrapWith<I, O, E, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -87,3 +97,62 @@ where
         UnwrapWith(self, f)
     }
 }
+
+/// Iterator unwrapping every `Result<T, E>`, replacing the errors with the default value.
+///
+/// Created with [`UnwrapOkOrDefaultExt::unwrap_ok_or_default`].
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct UnwrapOkOrDefault<I>(I);
+
+impl<I, O, E> Iterator for UnwrapOkOrDefault<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+    O: Default,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Result::unwrap_or_default)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to unwrap all the `Ok` values,
+/// replacing the errors with the default value.
+pub trait UnwrapOkOrDefaultExt<O, E>: Sized {
+    /// Unwraps all results, replacing every error with [`Default::default`]
+    ///
+    /// The errors are dropped, so they are not required to implement anything.
+    ///
+    /// ```
+    /// use resiter::unwrap::UnwrapOkOrDefaultExt;
+    ///
+    /// let unwrapped: Vec<usize> = vec![Ok(1), Err("x"), Ok(3)]
+    ///     .into_iter()
+    ///     .unwrap_ok_or_default()
+    ///     .collect();
+    ///
+    /// assert_eq!(unwrapped, [1, 0, 3]);
+    /// ```
+    fn unwrap_ok_or_default(self) -> UnwrapOkOrDefault<Self>
+    where
+        O: Default;
+}
+
+impl<I, O, E> UnwrapOkOrDefaultExt<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    #[inline]
+    fn unwrap_ok_or_default(self) -> UnwrapOkOrDefault<Self>
+    where
+        O: Default,
+    {
+        UnwrapOkOrDefault(self)
+    }
+}
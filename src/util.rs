@@ -44,3 +44,55 @@ impl<I: Iterator> Process<I::Item> for I {
         Ok(R::default())
     }
 }
+
+/// Iterator over the `Ok` values, stopping on the first `Err` and putting it aside.
+pub struct OkShunt<'a, I, E: 'a> {
+    iter: I,
+    error: &'a mut Option<E>,
+}
+
+impl<'a, I, T, E> Iterator for OkShunt<'a, I, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        match self.iter.next()? {
+            Ok(o) => Some(o),
+            Err(e) => {
+                *self.error = Some(e);
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.error.is_some() {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+/// Run the function over the `Ok` values till the first `Err`, which is returned instead.
+///
+/// The underlying iterator is never advanced past the first `Err`.
+pub fn try_process_oks<I, T, E, F, R>(iter: I, f: F) -> Result<R, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: for<'a> FnOnce(OkShunt<'a, I, E>) -> R,
+{
+    let mut error = None;
+    let res = f(OkShunt {
+        iter,
+        error: &mut error,
+    });
+    error.map_or(Ok(res), Err)
+}
@@ -40,6 +40,28 @@ pub trait WhileOk<O, E> {
     fn while_ok<F>(self, _: F) -> Result<(), E>
     where
         F: FnMut(O);
+
+    /// Perform an action on each `Ok` value. Stop on first `Err`,
+    /// same as [`while_ok`](WhileOk::while_ok), but also count the processed `Ok` values.
+    ///
+    /// ```
+    /// use resiter::while_ok::WhileOk;
+    /// use std::str::FromStr;
+    ///
+    /// let mut s = 0;
+    ///
+    /// let (processed, res) = ["1", "2", "a", "4", "5"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .while_ok_counted(|i| s += i);
+    ///
+    /// assert_eq!(s, 3);
+    /// assert_eq!(processed, 2);
+    /// assert!(res.is_err());
+    /// ```
+    fn while_ok_counted<F>(self, _: F) -> (usize, Result<(), E>)
+    where
+        F: FnMut(O);
 }
 
 impl<I, O, E> WhileOk<O, E> for I
@@ -56,4 +78,42 @@ where
         }
         Ok(())
     }
+
+    #[inline]
+    fn while_ok_counted<F>(self, mut f: F) -> (usize, Result<(), E>)
+    where
+        F: FnMut(O),
+    {
+        let mut processed = 0;
+        let res = self.while_ok(|o| {
+            processed += 1;
+            f(o)
+        });
+        (processed, res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_while_ok_counted_all_ok() {
+        let mut sum = 0;
+        let counted = vec![Ok::<_, &str>(1), Ok(2), Ok(3)]
+            .into_iter()
+            .while_ok_counted(|i| sum += i);
+
+        assert_eq!(counted, (3, Ok(())));
+        assert_eq!(sum, 6);
+    }
+
+    #[test]
+    fn test_while_ok_counted_stops_on_error() {
+        let mut iter = vec![Ok(1), Ok(2), Err("a"), Ok(3)].into_iter();
+        let counted = iter.by_ref().while_ok_counted(|_| {});
+
+        assert_eq!(counted, (2, Err("a")));
+        assert_eq!(iter.next(), Some(Ok(3)));
+    }
 }
@@ -0,0 +1,137 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#[cfg(not(test))]
+use core::cmp;
+#[cfg(test)]
+use std::cmp;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to pair the items
+/// with the ones from another `Iterator<Item = Result<U, E>>`.
+pub trait Zip<O, E>: Sized {
+    /// [Zip](Iterator::zip) the items of both iterators, pairing the `Ok` values.
+    ///
+    /// On every step one item is taken from each side:
+    /// - if both of them are `Ok`, the pair of values is yielded;
+    /// - if any of them is an `Err`, that `Err` is yielded
+    ///   (the left one, if both are `Err`),
+    ///   and the other item of the step is discarded.
+    ///
+    /// The iteration ends as soon as either of the sides ends.
+    /// Same as [`Iterator::zip`], if the left side ends first,
+    /// the right one is not advanced anymore.
+    ///
+    /// ```
+    /// use resiter::zip::Zip;
+    ///
+    /// let names = vec![Ok("alice"), Ok("bob"), Err("no name"), Ok("dave")];
+    /// let ages = vec![Ok(30), Err("no age"), Ok(25), Ok(40)];
+    ///
+    /// let zipped: Vec<_> = names.into_iter().zip_ok(ages).collect();
+    /// assert_eq!(
+    ///     zipped,
+    ///     [Ok(("alice", 30)), Err("no age"), Err("no name"), Ok(("dave", 40))]
+    /// );
+    /// ```
+    fn zip_ok<J, U>(self, other: J) -> ZipOk<Self, J::IntoIter>
+    where
+        J: IntoIterator<Item = Result<U, E>>;
+}
+
+impl<I, O, E> Zip<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn zip_ok<J, U>(self, other: J) -> ZipOk<Self, J::IntoIter>
+    where
+        J: IntoIterator<Item = Result<U, E>>,
+    {
+        ZipOk {
+            a: self,
+            b: other.into_iter(),
+        }
+    }
+}
+
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ZipOk<I, J> {
+    a: I,
+    b: J,
+}
+
+impl<I, J, O, U, E> Iterator for ZipOk<I, J>
+where
+    I: Iterator<Item = Result<O, E>>,
+    J: Iterator<Item = Result<U, E>>,
+{
+    type Item = Result<(O, U), E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.a.next()?;
+        let b = self.b.next()?;
+        Some(match (a, b) {
+            (Ok(a), Ok(b)) => Ok((a, b)),
+            (Err(e), _) | (_, Err(e)) => Err(e),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lo, a_hi) = self.a.size_hint();
+        let (b_lo, b_hi) = self.b.size_hint();
+        let hi = match (a_hi, b_hi) {
+            (Some(a), Some(b)) => Some(cmp::min(a, b)),
+            (Some(hi), None) | (None, Some(hi)) => Some(hi),
+            (None, None) => None,
+        };
+        (cmp::min(a_lo, b_lo), hi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zip_ok_error_on_the_left() {
+        let zipped: Vec<_> = vec![Ok(1), Err("a"), Ok(3)]
+            .into_iter()
+            .zip_ok(vec![Ok('x'), Ok('y'), Ok('z')])
+            .collect();
+
+        assert_eq!(zipped, [Ok((1, 'x')), Err("a"), Ok((3, 'z'))]);
+    }
+
+    #[test]
+    fn test_zip_ok_error_on_the_right() {
+        let zipped: Vec<_> = vec![Ok(1), Ok(2), Err("a")]
+            .into_iter()
+            .zip_ok(vec![Err("x"), Ok('y'), Err("z")])
+            .collect();
+
+        assert_eq!(zipped, [Err("x"), Ok((2, 'y')), Err("a")]);
+    }
+
+    #[test]
+    fn test_zip_ok_length_mismatch() {
+        let mut left = vec![Ok(1), Ok(2), Ok(3)].into_iter();
+        let zipped: Vec<_> = left.by_ref().zip_ok(vec![Ok::<_, &str>('x')]).collect();
+
+        assert_eq!(zipped, [Ok((1, 'x'))]);
+        // the left item of the last step was already taken
+        assert_eq!(left.next(), Some(Ok(3)));
+
+        let mut right = vec![Ok::<_, &str>('x'), Ok('y')].into_iter();
+        let zipped = vec![Ok(1)]
+            .into_iter()
+            .zip_ok(right.by_ref())
+            .collect::<Vec<_>>();
+
+        assert_eq!(zipped, [Ok((1, 'x'))]);
+        assert_eq!(right.next(), Some(Ok('y')));
+    }
+}